@@ -58,60 +58,7 @@ async fn main() {
         }
         println!();
     }
-    
-    // Demonstrate workload-based allocation
-    use backup_system::backup::dynamic_task_manager::DynamicTaskManager;
-    use backup_system::backup::{Directory, DirectoryStatus};
-    
-    println!("\n=== Workload-Based Allocation ===\n");
-    
-    // Create sample directories
-    let directories = vec![
-        Directory {
-            name: "Documents".to_string(),
-            path: "/home/user/Documents".into(),
-            size: 5_000_000_000, // 5GB
-            status: DirectoryStatus::Pending,
-            progress: 0,
-            selected: true,
-            start_time: None,
-            end_time: None,
-            files_processed: 0,
-            size_copied: 0,
-            file_count: Some(50000),
-            average_speed: None,
-        },
-        Directory {
-            name: "Videos".to_string(),
-            path: "/home/user/Videos".into(),
-            size: 50_000_000_000, // 50GB
-            status: DirectoryStatus::Pending,
-            progress: 0,
-            selected: true,
-            start_time: None,
-            end_time: None,
-            files_processed: 0,
-            size_copied: 0,
-            file_count: Some(100),
-            average_speed: None,
-        },
-    ];
-    
-    let workload = DynamicTaskManager::analyze_workload(&directories);
-    println!("Workload Analysis:");
-    println!("  Type: {:?}", workload.backup_type);
-    println!("  Total size: {} GB", workload.total_size / 1_073_741_824);
-    println!("  Directory count: {}", workload.directory_count);
-    println!("  Estimated files: {}", workload.file_count);
-    
-    let allocator = backup_system::utils::resource_monitor::SmartAllocator::new(monitor.clone());
-    let allocation = allocator.allocate_for_workload(&workload);
-    
-    println!("\nSmart Allocation:");
-    println!("  Recommended workers: {}", allocation.worker_count);
-    println!("  Memory per worker: {} MB", allocation.memory_per_worker_mb);
-    println!("  Priority hint: {}", allocation.priority_hint);
-    
+
     println!("\n✓ Dynamic scaling ensures optimal resource usage!");
     println!("✓ System adapts to workload characteristics!");
     println!("✓ Prevents resource exhaustion automatically!");
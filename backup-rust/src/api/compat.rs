@@ -17,29 +17,15 @@ pub fn compat_routes() -> Router<BackupManager> {
 }
 
 async fn start_backup(State(manager): State<BackupManager>) -> Json<serde_json::Value> {
-    // Verify mount before starting
+    // Verify the destination before starting: an S3 destination checks
+    // bucket reachability instead of the local mount point.
     let config = crate::utils::config::load_config().unwrap_or_default();
-    let backup_dest = config.backup_dest.parent()
-        .unwrap_or(&config.backup_dest)
-        .to_string_lossy();
-    
-    match crate::utils::disk::verify_backup_mount(&backup_dest).await {
-        Ok(true) => {
-            // Mount is verified, proceed
-        }
-        Ok(false) => {
-            return Json(json!({
-                "error": format!("USB drive is not mounted at {}. Please mount the drive and try again.", backup_dest)
-            }));
-        }
-        Err(e) => {
-            return Json(json!({
-                "error": format!("Failed to verify backup mount: {}", e)
-            }));
-        }
+
+    if let Err(e) = crate::backup::s3_task_processor::verify_backup_destination(&config).await {
+        return Json(json!({ "error": e }));
     }
-    
-    match manager.start(true).await {
+
+    match manager.start(config.default_parallelism).await {
         Ok(_) => Json(json!({"status": "started"})),
         Err(e) => Json(json!({"error": e.to_string()})),
     }
@@ -20,6 +20,15 @@ pub fn routes() -> Router<BackupManager> {
         .route("/select", post(select_directories))
         .route("/dryrun", post(set_dryrun))
         .route("/schedule", post(save_schedule))
+        .route("/prune", post(prune_backups))
+        .route("/workers", get(list_workers))
+        .route("/directories/cancel", post(cancel_directory))
+        .route("/tranquility", post(set_tranquility))
+        .route("/verify", post(control_verify))
+        .route("/config/reload", post(reload_config))
+        .route("/tasks/log", post(get_task_log))
+        .route("/directories/log", post(get_directory_log))
+        .route("/checkpoint", get(get_checkpoint))
         // Add compatibility routes for frontend
         .merge(compat::compat_routes())
 }
@@ -217,22 +226,38 @@ pub async fn get_status(State(manager): State<BackupManager>) -> Json<StatusResp
         None
     };
     
-    // Calculate current operation if backup is running
+    // Calculate current operation if backup is running, aggregating across
+    // every directory currently Active rather than assuming just one (the
+    // worker pool can run several transfers concurrently).
     let current_operation = if matches!(status.state, BackupState::Running) {
-        if let Some(current_dir) = status.directories.iter()
-            .find(|d| d.status == crate::backup::DirectoryStatus::Active) {
-            
+        let active_dirs: Vec<_> = status.directories.iter()
+            .filter(|d| d.status == crate::backup::DirectoryStatus::Active)
+            .collect();
+
+        if !active_dirs.is_empty() {
             let elapsed = status.start_time
                 .map(|start| chrono::Utc::now().timestamp() - start)
                 .unwrap_or(0);
-            
+
             let speed = calculate_current_speed(&status);
-            
+
+            let name = if let [only] = active_dirs.as_slice() {
+                only.name.clone()
+            } else {
+                active_dirs.iter().map(|d| d.name.as_str()).collect::<Vec<_>>().join(", ")
+            };
+            let progress = (active_dirs.iter().map(|d| d.progress as u32).sum::<u32>()
+                / active_dirs.len() as u32) as u8;
+            let files_processed = active_dirs.iter().map(|d| d.files_processed).sum();
+            let bytes_processed: u64 = active_dirs.iter()
+                .filter_map(|d| d.bytes_processed)
+                .sum();
+
             Some(CurrentOperation {
-                name: current_dir.name.clone(),
-                progress: current_dir.progress,
-                files_processed: current_dir.files_processed,
-                size_copied: format_bytes(current_dir.bytes_processed.unwrap_or(0)),
+                name,
+                progress,
+                files_processed,
+                size_copied: format_bytes(bytes_processed),
                 time_elapsed: format_duration(elapsed as u64),
                 current_speed: format!("{:.1} MB/s", speed),
             })
@@ -293,39 +318,41 @@ pub async fn get_status(State(manager): State<BackupManager>) -> Json<StatusResp
 #[derive(Deserialize)]
 struct ControlRequest {
     action: String,
+    /// Number of directories to back up concurrently. Takes priority over `parallel` if both are set.
+    parallelism: Option<usize>,
+    /// Back-compat alias for `parallelism`: `true` maps to the configured auto
+    /// parallelism, `false` to sequential (1 at a time).
     parallel: Option<bool>,
 }
 
+/// Resolve the requested number of concurrent directory transfers, preferring
+/// the new `parallelism` field over the legacy `parallel` boolean.
+fn resolve_parallelism(config: &crate::utils::config::Config, req: &ControlRequest) -> usize {
+    if let Some(parallelism) = req.parallelism {
+        return parallelism.max(1);
+    }
+    match req.parallel {
+        Some(false) => 1,
+        _ => config.default_parallelism.max(1),
+    }
+}
+
 async fn control_backup(
     State(manager): State<BackupManager>,
     Json(req): Json<ControlRequest>,
 ) -> Json<serde_json::Value> {
-    // For start action, verify mount first
+    let config = crate::utils::config::load_config().unwrap_or_default();
+
+    // For start action, verify the destination first: an S3 destination
+    // checks bucket reachability instead of the local mount point.
     if req.action == "start" {
-        let config = crate::utils::config::load_config().unwrap_or_default();
-        let backup_dest = config.backup_dest.parent()
-            .unwrap_or(&config.backup_dest)
-            .to_string_lossy();
-        
-        match crate::utils::disk::verify_backup_mount(&backup_dest).await {
-            Ok(true) => {
-                // Mount is verified, proceed
-            }
-            Ok(false) => {
-                return Json(serde_json::json!({
-                    "error": format!("USB drive is not mounted at {}. Please mount the drive and try again.", backup_dest)
-                }));
-            }
-            Err(e) => {
-                return Json(serde_json::json!({
-                    "error": format!("Failed to verify backup mount: {}", e)
-                }));
-            }
+        if let Err(e) = crate::backup::s3_task_processor::verify_backup_destination(&config).await {
+            return Json(serde_json::json!({ "error": e }));
         }
     }
-    
+
     let result = match req.action.as_str() {
-        "start" => manager.start(req.parallel.unwrap_or(true)).await,
+        "start" => manager.start(resolve_parallelism(&config, &req)).await,
         "pause" => manager.pause().await,
         "stop" => manager.stop().await,
         _ => return Json(serde_json::json!({"error": "Invalid action"})),
@@ -333,7 +360,15 @@ async fn control_backup(
     
     match result {
         Ok(_) => Json(serde_json::json!({"status": "ok"})),
-        Err(e) => Json(serde_json::json!({"error": e.to_string()})),
+        Err(e) => {
+            if let Some(control_err) = e.downcast_ref::<crate::backup::manager::ControlError>() {
+                return Json(serde_json::json!({
+                    "error": control_err.to_string(),
+                    "code": control_err.code(),
+                }));
+            }
+            Json(serde_json::json!({"error": e.to_string()}))
+        }
     }
 }
 
@@ -361,14 +396,16 @@ fn format_duration(seconds: u64) -> String {
 }
 
 fn calculate_current_speed(status: &crate::backup::manager::BackupStatus) -> f64 {
-    // First try to get speed from active directory
-    if let Some(active_dir) = status.directories.iter()
-        .find(|d| d.status == crate::backup::DirectoryStatus::Active) {
-        if let Some(speed) = active_dir.average_speed {
-            return speed as f64 / 1_048_576.0; // Convert to MB/s
-        }
+    // First try to sum the speed of every directory currently being
+    // transferred, since several can be Active at once under the worker pool.
+    let active_speed: u64 = status.directories.iter()
+        .filter(|d| d.status == crate::backup::DirectoryStatus::Active)
+        .filter_map(|d| d.average_speed)
+        .sum();
+    if active_speed > 0 {
+        return active_speed as f64 / 1_048_576.0; // Convert to MB/s
     }
-    
+
     // Fallback: Calculate based on completed size and elapsed time
     if let Some(start_time) = status.start_time {
         let elapsed = chrono::Utc::now().timestamp() - start_time;
@@ -424,4 +461,139 @@ async fn set_dryrun(Json(_data): Json<serde_json::Value>) -> Json<serde_json::Va
 
 async fn save_schedule(Json(_data): Json<serde_json::Value>) -> Json<serde_json::Value> {
     Json(serde_json::json!({"status": "ok"}))
+}
+
+#[derive(Deserialize)]
+struct PruneRequest {
+    #[serde(flatten)]
+    policy: crate::backup::prune::RetentionPolicy,
+    #[serde(default)]
+    dry_run: bool,
+}
+
+async fn prune_backups(
+    State(manager): State<BackupManager>,
+    Json(req): Json<PruneRequest>,
+) -> Json<serde_json::Value> {
+    match crate::backup::prune::run_prune(manager.storage(), &manager.config(), &req.policy, req.dry_run).await {
+        Ok(plan) => Json(serde_json::json!({"status": "ok", "plan": plan})),
+        Err(e) => Json(serde_json::json!({"error": e.to_string()})),
+    }
+}
+
+// Garage-style "list running workers and whether they are active, idle, or dead".
+async fn list_workers(State(manager): State<BackupManager>) -> Json<serde_json::Value> {
+    Json(serde_json::json!({ "workers": manager.list_workers() }))
+}
+
+#[derive(Deserialize)]
+struct CancelDirectoryRequest {
+    index: usize,
+}
+
+/// Aborts a single directory's transfer (e.g. a stuck large one) without
+/// stopping the rest of the running session.
+async fn cancel_directory(
+    State(manager): State<BackupManager>,
+    Json(req): Json<CancelDirectoryRequest>,
+) -> Json<serde_json::Value> {
+    if manager.cancel_directory(req.index) {
+        Json(serde_json::json!({"status": "ok"}))
+    } else {
+        Json(serde_json::json!({"error": "No queued or running task for that directory"}))
+    }
+}
+
+#[derive(Deserialize)]
+struct TranquilityRequest {
+    tranquility: u8,
+}
+
+/// Live IO-pacing knob: 0 = full speed, higher values make workers sleep
+/// longer between tasks relative to how long each one took.
+async fn set_tranquility(
+    State(manager): State<BackupManager>,
+    Json(req): Json<TranquilityRequest>,
+) -> Json<serde_json::Value> {
+    if let Err(e) = manager.set_tranquility(req.tranquility).await {
+        return Json(serde_json::json!({"status": "error", "message": e.to_string()}));
+    }
+    Json(serde_json::json!({"status": "ok", "tranquility": req.tranquility}))
+}
+
+#[derive(Deserialize)]
+struct VerifyRequest {
+    action: String,
+    /// Only read for `action: "start"` - re-check every `Completed`
+    /// directory instead of just the ones no previous pass verified.
+    #[serde(default)]
+    full: bool,
+}
+
+/// Drives the post-hoc content-hash scrub (`backup::verify::VerifyWorker`)
+/// the same way `/control` drives the main backup.
+async fn control_verify(
+    State(manager): State<BackupManager>,
+    Json(req): Json<VerifyRequest>,
+) -> Json<serde_json::Value> {
+    let result = match req.action.as_str() {
+        "start" => manager.start_verify(req.full).await,
+        "pause" => manager.pause_verify().await,
+        "resume" => manager.resume_verify().await,
+        "cancel" => manager.cancel_verify().await,
+        _ => return Json(serde_json::json!({"error": "Invalid action"})),
+    };
+
+    match result {
+        Ok(_) => Json(serde_json::json!({"status": "ok"})),
+        Err(e) => Json(serde_json::json!({"error": e.to_string()})),
+    }
+}
+
+/// Re-reads `backup.toml`/env vars and applies it to the next scan/start
+/// without restarting the process.
+async fn reload_config(State(manager): State<BackupManager>) -> Json<serde_json::Value> {
+    match manager.reload_config().await {
+        Ok(_) => Json(serde_json::json!({"status": "ok"})),
+        Err(e) => Json(serde_json::json!({"error": e.to_string()})),
+    }
+}
+
+#[derive(Deserialize)]
+struct TaskLogRequest {
+    task_id: u64,
+}
+
+/// Per-task structured log lines, keyed on the `task` span `worker_loop`
+/// opens around `process_task` - lets the UI show what a specific task did
+/// beyond the single `error` string in a failed status.
+async fn get_task_log(
+    State(manager): State<BackupManager>,
+    Json(req): Json<TaskLogRequest>,
+) -> Json<serde_json::Value> {
+    Json(serde_json::json!({ "log": manager.task_log(req.task_id) }))
+}
+
+#[derive(Deserialize)]
+struct DirectoryLogRequest {
+    name: String,
+}
+
+/// Every line `DirectoryFileLogLayer` has appended for one directory in the
+/// current session - unlike `/logs`, which is capped at the last 1000 lines
+/// shared across every directory, this has that directory's complete log.
+async fn get_directory_log(
+    State(manager): State<BackupManager>,
+    Json(req): Json<DirectoryLogRequest>,
+) -> Json<serde_json::Value> {
+    Json(serde_json::json!({ "log": manager.get_directory_log(&req.name).await }))
+}
+
+/// Latest resume cursor for the current session, so the UI can show
+/// "resumable from X" after a restart instead of assuming a full rescan.
+async fn get_checkpoint(State(manager): State<BackupManager>) -> Json<serde_json::Value> {
+    match manager.current_checkpoint().await {
+        Ok(checkpoint) => Json(serde_json::json!({ "checkpoint": checkpoint })),
+        Err(e) => Json(serde_json::json!({ "error": e.to_string() })),
+    }
 }
\ No newline at end of file
@@ -0,0 +1,180 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+use tracing::warn;
+
+/// The "resume cursor": the last directory a worker was transferring and how
+/// far it had gotten into it, durably persisted so an interrupted backup can
+/// pick back up close to where it crashed instead of re-running every
+/// directory from the start. Directory-level, not file-level, since that's
+/// the unit `TaskManager` schedules - `current_file`/`bytes_processed` below
+/// are the same in-flight progress fields `Directory` already carries, just
+/// snapshotted to disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub session_id: String,
+    pub directory_index: usize,
+    pub directory_name: String,
+    pub current_file: Option<String>,
+    pub bytes_processed: u64,
+    pub files_processed: u64,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// How often `CheckpointManager` is willing to persist a new checkpoint.
+/// A write happens once `flush_interval` has elapsed *or* either of the
+/// file/byte thresholds has been crossed since the last flush; below all
+/// three, progress ticks are skipped, so a worker streaming thousands of
+/// small files doesn't turn into thousands of disk writes.
+#[derive(Debug, Clone)]
+pub struct CheckpointConfig {
+    pub flush_interval: Duration,
+    pub flush_every_files: u64,
+    pub flush_every_bytes: u64,
+}
+
+impl Default for CheckpointConfig {
+    fn default() -> Self {
+        Self {
+            flush_interval: Duration::from_secs(30),
+            flush_every_files: 100,
+            flush_every_bytes: 50 * 1024 * 1024,
+        }
+    }
+}
+
+/// Pluggable checkpoint persistence, mirroring the `TaskStore`/`Repo` split:
+/// kept separate from both since a checkpoint is flushed far more often than
+/// a task snapshot (every N files/bytes within a single directory) and
+/// carries none of the queue-level state `TaskStore` owns.
+#[async_trait]
+pub trait CheckpointStore: Send + Sync {
+    async fn save(&self, checkpoint: &Checkpoint) -> Result<()>;
+    async fn load(&self, session_id: &str) -> Result<Option<Checkpoint>>;
+}
+
+/// Default `CheckpointStore`: a single JSON file, written to a temp path and
+/// `fsync`'d before being renamed into place, so a reader never observes a
+/// half-written checkpoint and a crash mid-write leaves the previous one intact.
+pub struct JsonFileCheckpointStore {
+    path: PathBuf,
+}
+
+impl JsonFileCheckpointStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+#[async_trait]
+impl CheckpointStore for JsonFileCheckpointStore {
+    async fn save(&self, checkpoint: &Checkpoint) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        let json = serde_json::to_vec_pretty(checkpoint)?;
+        let tmp_path = self.path.with_extension("json.tmp");
+
+        let mut file = tokio::fs::File::create(&tmp_path).await?;
+        file.write_all(&json).await?;
+        file.sync_all().await?;
+        drop(file);
+
+        tokio::fs::rename(&tmp_path, &self.path).await?;
+        Ok(())
+    }
+
+    async fn load(&self, session_id: &str) -> Result<Option<Checkpoint>> {
+        match tokio::fs::read(&self.path).await {
+            Ok(bytes) => {
+                let checkpoint: Checkpoint = serde_json::from_slice(&bytes)?;
+                if checkpoint.session_id == session_id {
+                    Ok(Some(checkpoint))
+                } else {
+                    Ok(None)
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// How far things stood as of the last successful flush, so `record_progress`
+/// can tell whether a threshold has been crossed since then.
+struct FlushState {
+    at: Instant,
+    files_processed: u64,
+    bytes_processed: u64,
+}
+
+/// Buffers in-flight directory progress and flushes it to a `CheckpointStore`
+/// no more often than `CheckpointConfig` allows. The flushed cursor is only
+/// considered advanced once `store.save` returns `Ok` - a failed write leaves
+/// the in-memory flush state untouched, so the next call retries rather than
+/// silently believing stale progress is durable.
+pub struct CheckpointManager {
+    store: Arc<dyn CheckpointStore>,
+    config: CheckpointConfig,
+    last_flush: Mutex<Option<FlushState>>,
+}
+
+impl CheckpointManager {
+    pub fn new(store: Arc<dyn CheckpointStore>, config: CheckpointConfig) -> Self {
+        Self {
+            store,
+            config,
+            last_flush: Mutex::new(None),
+        }
+    }
+
+    /// Record a worker's current position and flush it if enough has
+    /// changed since the last durable write. A no-op (cheap) call on every
+    /// progress tick; the actual IO only happens once a threshold is crossed.
+    pub async fn record_progress(&self, checkpoint: Checkpoint) {
+        let should_flush = {
+            let last_flush = self.last_flush.lock().unwrap();
+            match &*last_flush {
+                None => true,
+                Some(last) => {
+                    last.at.elapsed() >= self.config.flush_interval
+                        || checkpoint.files_processed.saturating_sub(last.files_processed)
+                            >= self.config.flush_every_files
+                        || checkpoint.bytes_processed.saturating_sub(last.bytes_processed)
+                            >= self.config.flush_every_bytes
+                }
+            }
+        };
+
+        if !should_flush {
+            return;
+        }
+
+        let files_processed = checkpoint.files_processed;
+        let bytes_processed = checkpoint.bytes_processed;
+
+        match self.store.save(&checkpoint).await {
+            Ok(()) => {
+                *self.last_flush.lock().unwrap() = Some(FlushState {
+                    at: Instant::now(),
+                    files_processed,
+                    bytes_processed,
+                });
+            }
+            Err(e) => {
+                warn!("Failed to flush checkpoint for session {}: {}", checkpoint.session_id, e);
+            }
+        }
+    }
+
+    /// The most recently *durably flushed* checkpoint for a session, used to
+    /// resume mid-directory and to answer the `/checkpoint` API route.
+    pub async fn latest(&self, session_id: &str) -> Result<Option<Checkpoint>> {
+        self.store.load(session_id).await
+    }
+}
@@ -0,0 +1,272 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// Free space on one of the configured backup destinations, refreshed each
+/// time `DiskLayout::refresh_free_space` runs.
+#[derive(Debug, Clone)]
+struct DiskSlot {
+    path: PathBuf,
+    free_bytes: u64,
+}
+
+/// Which destination disk each directory has been assigned to, persisted so
+/// an incremental re-run keeps copying a directory to the same disk instead
+/// of picking a new one every time free space shifts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DiskLayoutState {
+    placements: HashMap<String, PathBuf>,
+}
+
+/// Spreads directories across multiple destination disks with a greedy
+/// most-free-space placement: a not-yet-placed directory goes to whichever
+/// configured disk currently has the most free space and room for it, and
+/// the choice is remembered so later incremental runs stay pinned to the
+/// same disk instead of drifting as free space changes.
+pub struct DiskLayout {
+    slots: Vec<DiskSlot>,
+    state: DiskLayoutState,
+}
+
+impl DiskLayout {
+    pub fn new(destinations: Vec<PathBuf>, state: DiskLayoutState) -> Self {
+        let slots = destinations
+            .into_iter()
+            .map(|path| DiskSlot { path, free_bytes: 0 })
+            .collect();
+        Self { slots, state }
+    }
+
+    /// Re-measure free space on every configured disk via `df`. Cheap enough
+    /// to call before a placement decision - one `df` invocation per disk.
+    pub async fn refresh_free_space(&mut self) {
+        for slot in &mut self.slots {
+            match measure_free_bytes(&slot.path).await {
+                Ok(free) => slot.free_bytes = free,
+                Err(e) => warn!("Failed to measure free space on {}: {}", slot.path.display(), e),
+            }
+        }
+    }
+
+    /// Candidate disks for `directory_name`, most-preferred first: the disk
+    /// it's already pinned to (if any), then the rest with room for `size`
+    /// ordered by descending free space. The caller should try each in turn
+    /// (e.g. skipping ones that fail a connection check) and call
+    /// `confirm_placement` once one of them is actually usable.
+    pub fn candidates(&self, directory_name: &str, size: u64) -> Vec<PathBuf> {
+        let pinned = self.state.placements.get(directory_name);
+
+        let mut rest: Vec<&DiskSlot> = self
+            .slots
+            .iter()
+            .filter(|slot| Some(&slot.path) != pinned)
+            .filter(|slot| slot.free_bytes >= size)
+            .collect();
+        rest.sort_by(|a, b| b.free_bytes.cmp(&a.free_bytes));
+
+        let mut ordered: Vec<PathBuf> = Vec::with_capacity(rest.len() + 1);
+        if let Some(pinned) = pinned {
+            ordered.push(pinned.clone());
+        }
+        ordered.extend(rest.into_iter().map(|slot| slot.path.clone()));
+        ordered
+    }
+
+    /// Pin `directory_name` to `path` and account for its size against that
+    /// disk's free space, so the next directory's `candidates()` call sees
+    /// an up-to-date picture without a fresh round of `df` calls.
+    pub fn confirm_placement(&mut self, directory_name: &str, path: &Path, size: u64) {
+        self.state.placements.insert(directory_name.to_string(), path.to_path_buf());
+        if let Some(slot) = self.slots.iter_mut().find(|slot| slot.path == path) {
+            slot.free_bytes = slot.free_bytes.saturating_sub(size);
+        }
+    }
+
+    pub fn state(&self) -> DiskLayoutState {
+        self.state.clone()
+    }
+}
+
+/// Free bytes available at `path`, via `df` - the same shelling-out approach
+/// `utils::disk::get_path_stats` uses, rather than pulling in a disk-space crate.
+async fn measure_free_bytes(path: &Path) -> Result<u64> {
+    tokio::fs::create_dir_all(path).await?;
+
+    let output = Command::new("df")
+        .args(["-B1", "--output=avail"])
+        .arg(path)
+        .output()
+        .await?;
+
+    if !output.status.success() {
+        return Err(anyhow::anyhow!("df exited with {}", output.status));
+    }
+
+    let output_str = String::from_utf8_lossy(&output.stdout);
+    output_str
+        .lines()
+        .nth(1)
+        .and_then(|line| line.trim().parse::<u64>().ok())
+        .ok_or_else(|| anyhow::anyhow!("could not parse df output for {}", path.display()))
+}
+
+/// Pluggable persistence for `DiskLayoutState`, mirroring `CheckpointStore`.
+#[async_trait]
+pub trait DiskLayoutStore: Send + Sync {
+    async fn save(&self, state: &DiskLayoutState) -> Result<()>;
+    async fn load(&self) -> Result<Option<DiskLayoutState>>;
+}
+
+/// Default `DiskLayoutStore`: a single JSON file, written to a temp path and
+/// `fsync`'d before being renamed into place, so a reader never observes a
+/// half-written layout and a crash mid-write leaves the previous one intact.
+pub struct JsonFileDiskLayoutStore {
+    path: PathBuf,
+}
+
+impl JsonFileDiskLayoutStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+#[async_trait]
+impl DiskLayoutStore for JsonFileDiskLayoutStore {
+    async fn save(&self, state: &DiskLayoutState) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        let json = serde_json::to_vec_pretty(state)?;
+        let tmp_path = self.path.with_extension("json.tmp");
+
+        let mut file = tokio::fs::File::create(&tmp_path).await?;
+        file.write_all(&json).await?;
+        file.sync_all().await?;
+        drop(file);
+
+        tokio::fs::rename(&tmp_path, &self.path).await?;
+        Ok(())
+    }
+
+    async fn load(&self) -> Result<Option<DiskLayoutState>> {
+        match tokio::fs::read(&self.path).await {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout_with_free_space(sizes: &[(&str, u64)]) -> DiskLayout {
+        let mut layout = DiskLayout::new(
+            sizes.iter().map(|(p, _)| PathBuf::from(p)).collect(),
+            DiskLayoutState::default(),
+        );
+        for (slot, (_, free_bytes)) in layout.slots.iter_mut().zip(sizes.iter()) {
+            slot.free_bytes = *free_bytes;
+        }
+        layout
+    }
+
+    #[test]
+    fn candidates_orders_by_descending_free_space() {
+        let layout = layout_with_free_space(&[("/a", 100), ("/b", 300), ("/c", 200)]);
+        let candidates = layout.candidates("dir", 50);
+        assert_eq!(candidates, vec![PathBuf::from("/b"), PathBuf::from("/c"), PathBuf::from("/a")]);
+    }
+
+    #[test]
+    fn candidates_excludes_disks_without_room() {
+        let layout = layout_with_free_space(&[("/a", 100), ("/b", 300)]);
+        let candidates = layout.candidates("dir", 200);
+        assert_eq!(candidates, vec![PathBuf::from("/b")]);
+    }
+
+    #[test]
+    fn candidates_puts_pinned_disk_first_even_if_not_most_free() {
+        let mut layout = layout_with_free_space(&[("/a", 100), ("/b", 300)]);
+        layout.confirm_placement("dir", &PathBuf::from("/a"), 50);
+        // /a now has less free space than /b, but an already-placed directory
+        // should keep being offered its existing disk first.
+        let candidates = layout.candidates("dir", 10);
+        assert_eq!(candidates[0], PathBuf::from("/a"));
+    }
+
+    #[test]
+    fn confirm_placement_deducts_size_from_that_disks_free_space() {
+        let mut layout = layout_with_free_space(&[("/a", 300)]);
+        layout.confirm_placement("dir", &PathBuf::from("/a"), 100);
+        assert_eq!(layout.slots[0].free_bytes, 200);
+        assert_eq!(layout.state.placements.get("dir"), Some(&PathBuf::from("/a")));
+    }
+
+    #[test]
+    fn confirm_placement_never_underflows_free_space() {
+        let mut layout = layout_with_free_space(&[("/a", 50)]);
+        layout.confirm_placement("dir", &PathBuf::from("/a"), 500);
+        assert_eq!(layout.slots[0].free_bytes, 0);
+    }
+}
+
+/// Shared handle wrapping a `DiskLayout` behind a lock, so every worker can
+/// resolve a destination disk and persist its choice without racing. Loading
+/// from `store` and the first `df` sweep are deferred to the first call
+/// rather than done in `new`, so construction (e.g. inside `BackupManager::new`)
+/// stays synchronous.
+pub struct DiskLayoutManager {
+    destinations: Vec<PathBuf>,
+    store: Arc<dyn DiskLayoutStore>,
+    layout: Mutex<Option<DiskLayout>>,
+}
+
+impl DiskLayoutManager {
+    pub fn new(destinations: Vec<PathBuf>, store: Arc<dyn DiskLayoutStore>) -> Self {
+        Self {
+            destinations,
+            store,
+            layout: Mutex::new(None),
+        }
+    }
+
+    async fn ensure_loaded(&self, guard: &mut Option<DiskLayout>) -> Result<()> {
+        if guard.is_none() {
+            let state = self.store.load().await?.unwrap_or_default();
+            let mut layout = DiskLayout::new(self.destinations.clone(), state);
+            layout.refresh_free_space().await;
+            *guard = Some(layout);
+        }
+        Ok(())
+    }
+
+    /// Candidate disks for `directory_name`, most-preferred first. See
+    /// `DiskLayout::candidates`.
+    pub async fn candidates(&self, directory_name: &str, size: u64) -> Result<Vec<PathBuf>> {
+        let mut guard = self.layout.lock().await;
+        self.ensure_loaded(&mut guard).await?;
+        Ok(guard.as_ref().unwrap().candidates(directory_name, size))
+    }
+
+    /// Pin `directory_name` to `path` and durably persist the updated layout.
+    pub async fn confirm_placement(&self, directory_name: &str, path: &Path, size: u64) -> Result<()> {
+        let mut guard = self.layout.lock().await;
+        self.ensure_loaded(&mut guard).await?;
+        let layout = guard.as_mut().unwrap();
+        layout.confirm_placement(directory_name, path, size);
+        let state = layout.state();
+        drop(guard);
+
+        self.store.save(&state).await
+    }
+}
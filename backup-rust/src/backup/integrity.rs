@@ -0,0 +1,294 @@
+use anyhow::Result;
+use blake2::digest::{Update, VariableOutput};
+use blake2::Blake2bVar;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+
+/// `verify_backup`'s default, matching Blake2b's usual 256-bit output; a
+/// caller can pass a different size (Blake2b supports up to 64 bytes) for a
+/// stronger or cheaper check.
+pub const DEFAULT_DIGEST_SIZE: usize = 32;
+
+/// A source file whose content digest didn't match its destination copy
+/// (or whose destination copy is missing entirely).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mismatch {
+    pub path: PathBuf,
+    pub source_digest: String,
+    pub dest_digest: Option<String>,
+}
+
+/// A file's last-verified digest plus the size/mtime it was computed
+/// against, so a later run can tell the source hasn't changed without
+/// re-reading it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ManifestEntry {
+    digest: String,
+    size: u64,
+    mtime: i64,
+}
+
+/// Per-directory digest cache, keyed by the file's path relative to
+/// `src_root`. Persisted as `<dst_root>/.backup_manifest.json`, alongside
+/// the files it describes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Manifest {
+    entries: HashMap<String, ManifestEntry>,
+}
+
+/// Name of the digest-cache file `verify_backup` keeps alongside the files
+/// it describes. Exposed so other walkers (e.g. the S3 uploader) can avoid
+/// treating this as a user file that needs backing up in its own right.
+pub const MANIFEST_FILE_NAME: &str = ".backup_manifest.json";
+
+fn manifest_path(dst_root: &Path) -> PathBuf {
+    dst_root.join(MANIFEST_FILE_NAME)
+}
+
+async fn load_manifest(dst_root: &Path) -> Result<Manifest> {
+    match tokio::fs::read(manifest_path(dst_root)).await {
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Manifest::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Written to a temp path and renamed into place, the same crash-safe
+/// pattern `JsonFileCheckpointStore`/`JsonFileDiskLayoutStore` use, so a
+/// crash mid-write leaves the previous manifest intact.
+async fn save_manifest(dst_root: &Path, manifest: &Manifest) -> Result<()> {
+    let path = manifest_path(dst_root);
+    let tmp_path = path.with_extension("json.tmp");
+
+    let json = serde_json::to_vec_pretty(manifest)?;
+    let mut file = tokio::fs::File::create(&tmp_path).await?;
+    file.write_all(&json).await?;
+    file.sync_all().await?;
+    drop(file);
+
+    tokio::fs::rename(&tmp_path, &path).await?;
+    Ok(())
+}
+
+/// All regular files under `root`, as absolute paths. Walked iteratively
+/// (an explicit stack rather than recursive `async fn`) since recursive
+/// `async fn`s need boxing to have a known size.
+async fn walk_files(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let mut read_dir = match tokio::fs::read_dir(&dir).await {
+            Ok(read_dir) => read_dir,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        while let Some(entry) = read_dir.next_entry().await? {
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                dirs.push(entry.path());
+            } else if file_type.is_file() {
+                files.push(entry.path());
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Hashes `path` with Blake2b, in 1 MiB chunks rather than byte-by-byte, so
+/// verifying a multi-gigabyte file doesn't mean a syscall per byte. Runs on
+/// a blocking thread since this is synchronous, CPU-bound work.
+async fn hash_file(path: PathBuf, digest_size: usize) -> Result<Vec<u8>> {
+    tokio::task::spawn_blocking(move || {
+        let mut hasher = Blake2bVar::new(digest_size)
+            .map_err(|e| anyhow::anyhow!("invalid Blake2b digest size {}: {}", digest_size, e))?;
+
+        let file = std::fs::File::open(&path)?;
+        let mut reader = std::io::BufReader::with_capacity(1024 * 1024, file);
+        let mut buf = vec![0u8; 1024 * 1024];
+
+        loop {
+            let n = reader.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buf[..n]);
+        }
+
+        let mut digest = vec![0u8; digest_size];
+        hasher
+            .finalize_variable(&mut digest)
+            .map_err(|e| anyhow::anyhow!("Blake2b finalize failed for {}: {}", path.display(), e))?;
+        Ok(digest)
+    })
+    .await?
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Computes a Blake2b digest of every file under `src_root` and its
+/// counterpart under `dst_root`, reporting any that don't match (including
+/// a destination file that's missing entirely). Source files whose size
+/// and mtime match the stored manifest entry reuse that digest instead of
+/// being re-read, since the source is what the manifest tracks - the
+/// destination is always re-hashed, since catching its silent corruption
+/// is the whole point of this check. `on_progress(done, total)` is called
+/// after each file so a caller can surface per-file progress.
+pub async fn verify_backup(
+    src_root: &Path,
+    dst_root: &Path,
+    digest_size: usize,
+    mut on_progress: impl FnMut(usize, usize),
+) -> Result<Vec<Mismatch>> {
+    let mut manifest = load_manifest(dst_root).await?;
+    let files = walk_files(src_root).await?;
+    let total = files.len();
+    let mut mismatches = Vec::new();
+
+    for (done, src_path) in files.into_iter().enumerate() {
+        let relative = src_path
+            .strip_prefix(src_root)
+            .unwrap_or(&src_path)
+            .to_string_lossy()
+            .to_string();
+        let dst_path = dst_root.join(&relative);
+
+        let metadata = tokio::fs::metadata(&src_path).await?;
+        let size = metadata.len();
+        let mtime = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+
+        let source_digest = match manifest.entries.get(&relative) {
+            Some(entry) if entry.size == size && entry.mtime == mtime => entry.digest.clone(),
+            _ => {
+                let digest = to_hex(&hash_file(src_path.clone(), digest_size).await?);
+                manifest.entries.insert(
+                    relative.clone(),
+                    ManifestEntry { digest: digest.clone(), size, mtime },
+                );
+                digest
+            }
+        };
+
+        let dest_digest = if tokio::fs::try_exists(&dst_path).await.unwrap_or(false) {
+            Some(to_hex(&hash_file(dst_path, digest_size).await?))
+        } else {
+            None
+        };
+
+        if dest_digest.as_deref() != Some(source_digest.as_str()) {
+            mismatches.push(Mismatch {
+                path: src_path,
+                source_digest,
+                dest_digest,
+            });
+        }
+
+        on_progress(done + 1, total);
+    }
+
+    save_manifest(dst_root, &manifest).await?;
+
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_temp_dir(label: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "backup2usb-integrity-test-{}-{}",
+            label,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[tokio::test]
+    async fn identical_trees_produce_no_mismatches() {
+        let src = unique_temp_dir("identical-src");
+        let dst = unique_temp_dir("identical-dst");
+        tokio::fs::create_dir_all(&src).await.unwrap();
+        tokio::fs::write(src.join("a.txt"), b"same content").await.unwrap();
+        tokio::fs::create_dir_all(&dst).await.unwrap();
+        tokio::fs::write(dst.join("a.txt"), b"same content").await.unwrap();
+
+        let mismatches = verify_backup(&src, &dst, DEFAULT_DIGEST_SIZE, |_, _| {}).await.unwrap();
+        assert!(mismatches.is_empty());
+
+        tokio::fs::remove_dir_all(&src).await.unwrap();
+        tokio::fs::remove_dir_all(&dst).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn differing_content_is_reported_as_a_mismatch() {
+        let src = unique_temp_dir("differ-src");
+        let dst = unique_temp_dir("differ-dst");
+        tokio::fs::create_dir_all(&src).await.unwrap();
+        tokio::fs::write(src.join("a.txt"), b"source content").await.unwrap();
+        tokio::fs::create_dir_all(&dst).await.unwrap();
+        tokio::fs::write(dst.join("a.txt"), b"corrupted content").await.unwrap();
+
+        let mismatches = verify_backup(&src, &dst, DEFAULT_DIGEST_SIZE, |_, _| {}).await.unwrap();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].path, src.join("a.txt"));
+        assert!(mismatches[0].dest_digest.is_some());
+        assert_ne!(mismatches[0].source_digest, mismatches[0].dest_digest.clone().unwrap());
+
+        tokio::fs::remove_dir_all(&src).await.unwrap();
+        tokio::fs::remove_dir_all(&dst).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn a_missing_destination_file_is_reported_with_no_dest_digest() {
+        let src = unique_temp_dir("missing-src");
+        let dst = unique_temp_dir("missing-dst");
+        tokio::fs::create_dir_all(&src).await.unwrap();
+        tokio::fs::write(src.join("only-on-source.txt"), b"never copied").await.unwrap();
+        tokio::fs::create_dir_all(&dst).await.unwrap();
+
+        let mismatches = verify_backup(&src, &dst, DEFAULT_DIGEST_SIZE, |_, _| {}).await.unwrap();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].dest_digest, None);
+
+        tokio::fs::remove_dir_all(&src).await.unwrap();
+        tokio::fs::remove_dir_all(&dst).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn a_second_run_reuses_the_cached_source_digest_via_the_manifest() {
+        let src = unique_temp_dir("cache-src");
+        let dst = unique_temp_dir("cache-dst");
+        tokio::fs::create_dir_all(&src).await.unwrap();
+        tokio::fs::write(src.join("a.txt"), b"stable content").await.unwrap();
+        tokio::fs::create_dir_all(&dst).await.unwrap();
+        tokio::fs::write(dst.join("a.txt"), b"stable content").await.unwrap();
+
+        verify_backup(&src, &dst, DEFAULT_DIGEST_SIZE, |_, _| {}).await.unwrap();
+        let manifest = load_manifest(&dst).await.unwrap();
+        let cached = manifest.entries.get("a.txt").unwrap().digest.clone();
+
+        // A second run with unchanged size/mtime should report the same
+        // digest without needing to re-read the (unmodified) source file.
+        let mismatches = verify_backup(&src, &dst, DEFAULT_DIGEST_SIZE, |_, _| {}).await.unwrap();
+        assert!(mismatches.is_empty());
+        let manifest = load_manifest(&dst).await.unwrap();
+        assert_eq!(manifest.entries.get("a.txt").unwrap().digest, cached);
+
+        tokio::fs::remove_dir_all(&src).await.unwrap();
+        tokio::fs::remove_dir_all(&dst).await.unwrap();
+    }
+}
@@ -1,52 +1,198 @@
-use super::{BackupSession, BackupState, Directory, SharedSession, DirectoryStatus};
-use super::task_manager::TaskManager;
+use super::{BackupSession, BackupState, Directory, SharedSession, DirectoryStatus, Stage};
+use super::checkpoint::{Checkpoint, CheckpointConfig, CheckpointManager, CheckpointStore, JsonFileCheckpointStore};
+use super::disk_layout::{DiskLayoutManager, DiskLayoutStore, JsonFileDiskLayoutStore};
+use super::task_manager::{TaskManager, WorkerInfo};
 use super::task_processor::{BackupTaskProcessor, TaskProcessor};
+use super::s3_task_processor::{S3Destination, S3TaskProcessor};
+use super::task_log::TaskLogRegistry;
+use super::task_store::{JsonFileTaskStore, TaskStore};
+use super::verify::{JsonFileScrubStore, ScrubStore, VerifyWorker};
 use crate::storage::Storage;
 use crate::utils::config::Config;
-use crate::utils::disk::verify_backup_mount;
 use crate::utils::log_buffer::LogBuffer;
+use crate::utils::log_layer::WarningCounts;
 use anyhow::Result;
+use std::path::PathBuf;
+use std::sync::Mutex;
 use std::sync::RwLock;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio::sync::broadcast;
 use tracing::{debug, info, error, warn};
+use tracing::Instrument;
+
+/// Where the `TaskStore` snapshot lives: next to the sqlite database file if
+/// `database_url` is a `sqlite://` URL, otherwise a fixed relative path.
+fn task_state_path(config: &Config) -> PathBuf {
+    match config.database_url.strip_prefix("sqlite://") {
+        Some(db_path) => PathBuf::from(db_path).with_file_name("task_state.json"),
+        None => PathBuf::from("task_state.json"),
+    }
+}
+
+/// Where the resume-cursor checkpoint lives, alongside the `TaskStore` snapshot.
+fn checkpoint_path(config: &Config) -> PathBuf {
+    match config.database_url.strip_prefix("sqlite://") {
+        Some(db_path) => PathBuf::from(db_path).with_file_name("checkpoint.json"),
+        None => PathBuf::from("checkpoint.json"),
+    }
+}
+
+/// Where the multi-disk placement layout lives, alongside the checkpoint
+/// and `TaskStore` snapshot.
+fn disk_layout_path(config: &Config) -> PathBuf {
+    match config.database_url.strip_prefix("sqlite://") {
+        Some(db_path) => PathBuf::from(db_path).with_file_name("disk_layout.json"),
+        None => PathBuf::from("disk_layout.json"),
+    }
+}
+
+/// Where the last scrub pass's `ScrubSummary` lives, alongside the other
+/// small JSON-file-backed state.
+fn scrub_summary_path(config: &Config) -> PathBuf {
+    match config.database_url.strip_prefix("sqlite://") {
+        Some(db_path) => PathBuf::from(db_path).with_file_name("scrub_summary.json"),
+        None => PathBuf::from("scrub_summary.json"),
+    }
+}
+
+/// Mark directories the resume cursor says are already past as `Completed`
+/// so the scheduler in `process_commands` (which only queues `Pending`
+/// directories) skips them, and seed the directory the cursor was actually
+/// in the middle of with its last-known position so the UI reflects where
+/// the run picks back up.
+fn apply_checkpoint(session: &mut BackupSession, checkpoint: &Checkpoint) {
+    for (idx, dir) in session.directories.iter_mut().enumerate() {
+        if idx < checkpoint.directory_index && dir.status == DirectoryStatus::Pending {
+            dir.status = DirectoryStatus::Completed;
+            dir.progress = 100;
+        }
+    }
+
+    if let Some(dir) = session.directories.get_mut(checkpoint.directory_index) {
+        if dir.status == DirectoryStatus::Pending {
+            dir.current_file = checkpoint.current_file.clone();
+            dir.bytes_processed = Some(checkpoint.bytes_processed);
+            dir.files_processed = checkpoint.files_processed;
+        }
+    }
+}
+
+/// Structured errors surfaced by control operations, as opposed to the
+/// free-form `anyhow::Error`s used for internal plumbing failures.
+#[derive(Debug)]
+pub enum ControlError {
+    BackupAlreadyInProgress { session_id: String },
+}
+
+impl ControlError {
+    /// Stable machine-readable code the frontend can branch on.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ControlError::BackupAlreadyInProgress { .. } => "backup_already_in_progress",
+        }
+    }
+}
+
+impl std::fmt::Display for ControlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ControlError::BackupAlreadyInProgress { session_id } => {
+                write!(f, "A backup is already in progress (session {})", session_id)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ControlError {}
+
+/// Generate a collision-proof session id: a millisecond-precision timestamp
+/// followed by a random suffix, so rapid successive runs never collide on
+/// the `backup_sessions` primary key.
+fn generate_session_id() -> String {
+    format!(
+        "{:013}-{:08x}",
+        chrono::Utc::now().timestamp_millis(),
+        uuid::Uuid::new_v4().as_u128() as u32
+    )
+}
 
 #[derive(Clone)]
 pub struct BackupManager {
-    config: Arc<Config>,
+    /// Live config, swappable via `reload_config()` without a process
+    /// restart. Readers take a cheap `Arc<Config>` snapshot rather than
+    /// holding the lock.
+    config: Arc<RwLock<Arc<Config>>>,
     storage: Storage,
     session: SharedSession,
     command_tx: mpsc::Sender<Command>,
     event_tx: broadcast::Sender<Event>,
     task_manager: TaskManager,
     log_buffer: LogBuffer,
+    /// Per-directory warning counts, filled in by `DirectoryFileLogLayer`
+    /// from the `process_directory` span's events.
+    warning_counts: WarningCounts,
+    /// Process-wide run lock: holds the id of the currently running session,
+    /// if any, so overlapping `start` calls are rejected instead of racing.
+    running_session: Arc<Mutex<Option<String>>>,
+    /// Resume-cursor checkpoints, shared with `task_manager`'s workers and
+    /// consulted by `restore_session`/`scan_directories` and the `/checkpoint` route.
+    checkpoint_manager: Arc<CheckpointManager>,
+    /// Post-hoc content-hash scrub over completed directories, run out of
+    /// band from the main worker pool - see `super::verify`.
+    verify_worker: Arc<VerifyWorker>,
 }
 
 #[derive(Debug)]
 pub enum Command {
-    Start { parallel: bool },
+    Start { parallelism: usize },
     Pause,
+    Resume,
     Stop,
     UpdateProgress { index: usize, progress: u8 },
+    SetTranquility { value: u8 },
+    /// Kick off a verify pass over `Completed` directories. `full` re-checks
+    /// everything; otherwise only directories not verified by a previous
+    /// pass (including ones recorded by an earlier process) are checked.
+    Verify { full: bool },
+    PauseVerify,
+    ResumeVerify,
+    CancelVerify,
+    /// A newly-reloaded config, applied to the next `Start`/scan rather
+    /// than the one currently running.
+    ReloadConfig(Config),
 }
 
 #[derive(Debug, Clone)]
 pub enum Event {
     StateChanged(BackupState),
-    ProgressUpdate { index: usize, progress: u8 },
+    ProgressUpdate { index: usize, progress: u8, stage: Stage },
     DirectoryCompleted { index: usize },
     Error { message: String },
+    /// A worker transitioned between idle/busy/dead, per `TaskManager::list_workers`.
+    WorkerStateChanged { id: usize, info: super::task_manager::WorkerInfo },
+    /// The IO tranquility knob was changed live via `Command::SetTranquility`.
+    TranquilityChanged { value: u8 },
+    /// `VerifyWorker` finished hashing one more file for the directory at `index`.
+    VerifyProgress { index: usize, progress: u8 },
+    /// `DirectoryScanner` finished sizing one more top-level directory.
+    ScanProgress { index: usize, total: usize, name: String, size: u64, file_count: u64 },
 }
 
 impl BackupManager {
-    pub fn new(config: Config, storage: Storage) -> Self {
+    pub fn new(
+        config: Config,
+        storage: Storage,
+        task_log_registry: TaskLogRegistry,
+        log_buffer: LogBuffer,
+        warning_counts: WarningCounts,
+    ) -> Self {
         let (command_tx, command_rx) = mpsc::channel(32);
         let (event_tx, _) = broadcast::channel(128);
         
         // Initialize or load session
         let session = Arc::new(RwLock::new(BackupSession {
-            id: uuid::Uuid::new_v4().to_string(),
+            id: generate_session_id(),
             directories: Vec::new(),
             current_index: 0,
             total_size: 0,
@@ -54,6 +200,7 @@ impl BackupManager {
             start_time: None,
             state: BackupState::Stopped,
             errors: Vec::new(),
+            tranquility: config.tranquility,
         }));
 
         // Initialize task manager
@@ -61,18 +208,43 @@ impl BackupManager {
             config.max_workers,
             num_cpus::get() / 2, // Use half the cores for efficiency
         );
-        let task_manager = TaskManager::new(num_workers);
-        
-        let log_buffer = LogBuffer::new(1000); // Keep last 1000 log entries
-        
+        let mut task_manager = TaskManager::new(num_workers);
+        let task_store = Arc::new(JsonFileTaskStore::new(task_state_path(&config))) as Arc<dyn TaskStore>;
+        task_manager.set_task_store(task_store);
+        task_manager.set_task_log_registry(task_log_registry);
+        task_manager.set_event_tx(event_tx.clone());
+        task_manager.set_tranquility(config.tranquility as u32);
+
+        let checkpoint_store = Arc::new(JsonFileCheckpointStore::new(checkpoint_path(&config))) as Arc<dyn CheckpointStore>;
+        let checkpoint_manager = Arc::new(CheckpointManager::new(checkpoint_store, CheckpointConfig::default()));
+        task_manager.set_checkpoint_manager(checkpoint_manager.clone());
+
+        // Multi-disk placement only kicks in once more than one destination
+        // is configured - a single destination keeps the old behavior of
+        // writing straight to `backup_dest`.
+        if config.backup_destinations.len() > 1 {
+            let disk_layout_store = Arc::new(JsonFileDiskLayoutStore::new(disk_layout_path(&config))) as Arc<dyn DiskLayoutStore>;
+            let disk_layout_manager = Arc::new(DiskLayoutManager::new(config.backup_destinations.clone(), disk_layout_store));
+            task_manager.set_disk_layout(disk_layout_manager);
+        }
+
+        let running_session = Arc::new(Mutex::new(None));
+        let tranquility_handle = task_manager.tranquility_handle();
+        let scrub_store = Arc::new(JsonFileScrubStore::new(scrub_summary_path(&config))) as Arc<dyn ScrubStore>;
+        let verify_worker = Arc::new(VerifyWorker::with_scrub_store(scrub_store));
+
         let manager = Self {
-            config: Arc::new(config),
+            config: Arc::new(RwLock::new(Arc::new(config))),
             storage: storage.clone(),
             session: session.clone(),
             command_tx,
             event_tx: event_tx.clone(),
             task_manager,
             log_buffer: log_buffer.clone(),
+            warning_counts,
+            running_session: running_session.clone(),
+            checkpoint_manager,
+            verify_worker: verify_worker.clone(),
         };
 
         // Remove the event listener to prevent deadlock
@@ -84,11 +256,35 @@ impl BackupManager {
             session,
             storage,
             event_tx,
-            manager.config.clone(),
+            manager.config.read().unwrap().clone(),
             manager.task_manager.clone(),
             log_buffer,
+            running_session,
+            verify_worker,
+            tranquility_handle,
         ));
 
+        // Periodic incremental scrub, gated on `scrub_interval_secs` (0 =
+        // disabled) - reuses the same `Command::Verify` path `/verify` does,
+        // just on a timer instead of an operator request.
+        let scrub_command_tx = manager.command_tx.clone();
+        let scrub_config = manager.config.clone();
+        tokio::spawn(async move {
+            loop {
+                let interval_secs = scrub_config.read().unwrap().scrub_interval_secs;
+                if interval_secs == 0 {
+                    tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+                    continue;
+                }
+
+                tokio::time::sleep(std::time::Duration::from_secs(interval_secs)).await;
+                info!("Starting scheduled scrub pass");
+                if scrub_command_tx.send(Command::Verify { full: false }).await.is_err() {
+                    break;
+                }
+            }
+        });
+
         manager
     }
 
@@ -97,59 +293,78 @@ impl BackupManager {
         session: SharedSession,
         storage: Storage,
         event_tx: broadcast::Sender<Event>,
-        config: Arc<Config>,
+        mut config: Arc<Config>,
         mut task_manager: TaskManager,
         log_buffer: LogBuffer,
+        running_session: Arc<Mutex<Option<String>>>,
+        verify_worker: Arc<VerifyWorker>,
+        tranquility_handle: Arc<std::sync::atomic::AtomicU32>,
     ) {
-        // Set up the task processor with event channel and log buffer
-        let task_processor = Arc::new(BackupTaskProcessor::with_log_buffer(event_tx.clone(), log_buffer.clone())) as Arc<dyn TaskProcessor>;
+        // Set up the task processor with the event channel. An S3
+        // destination in config takes over from the local USB mount,
+        // through the same `TaskProcessor` trait so the rest of the worker
+        // pool and metrics path is unaffected.
+        let task_processor: Arc<dyn TaskProcessor> = match S3Destination::from_config(&config) {
+            Some(destination) => {
+                info!("Using S3 destination: bucket={}", destination.bucket);
+                Arc::new(S3TaskProcessor::new(destination))
+            }
+            None => Arc::new(BackupTaskProcessor::new(event_tx.clone())),
+        };
         task_manager.set_task_processor(task_processor);
         
         let _event_tx_clone = event_tx.clone();
 
         while let Some(cmd) = rx.recv().await {
             match cmd {
-                Command::Start { parallel } => {
-                    info!("Starting backup (parallel: {})", parallel);
-                    log_buffer.add_log("info", format!("Starting backup (parallel: {})", parallel), None);
+                Command::Start { parallelism } => {
+                    // Open a per-session span so every info!/warn!/error! below
+                    // (and in the workers it spawns) is tagged with session_id
+                    // for the DbLogLayer without passing it explicitly.
+                    let session_id = session.read().unwrap().id.clone();
+                    let session_span = tracing::info_span!("backup_session", session_id = %session_id);
+                    let _session_guard = session_span.enter();
+
+                    info!("Starting backup (parallelism: {})", parallelism);
+                    log_buffer.add_log("info", format!("Starting backup (parallelism: {})", parallelism), None);
                     log_buffer.add_log("info", "Backup process initiated".to_string(), None);
                     log_buffer.add_log("info", "Scanning directories...".to_string(), None);
                     
-                    // Verify backup mount before starting
-                    let backup_dest = config.backup_dest.parent()
-                        .unwrap_or(&config.backup_dest)
-                        .to_string_lossy();
-                    
-                    match verify_backup_mount(&backup_dest).await {
-                        Ok(true) => {
-                            info!("Backup mount verified at: {}", backup_dest);
-                            
-                            // Create the backup destination directory if it doesn't exist
-                            if let Err(e) = tokio::fs::create_dir_all(&config.backup_dest).await {
-                                error!("Failed to create backup directory: {}", e);
+                    // Verify the backup destination before starting: an S3
+                    // destination checks bucket reachability instead of the
+                    // local mount point, since there's no path to mount.
+                    if let Err(e) = super::s3_task_processor::verify_backup_destination(&config).await {
+                        error!("{}", e);
+                        let _ = event_tx.send(Event::Error { message: e });
+                        *running_session.lock().unwrap() = None;
+                        continue;
+                    }
+                    info!("Backup destination verified");
+
+                    // Local destinations also need the directory created -
+                    // S3 has no equivalent step since `put_object` creates
+                    // keys implicitly. With several destinations configured,
+                    // each one is created up front; per-directory placement
+                    // still skips whichever of them turn out unreachable.
+                    if S3Destination::from_config(&config).is_none() {
+                        let mut create_failed = false;
+                        for dest in &config.backup_destinations {
+                            if let Err(e) = tokio::fs::create_dir_all(dest).await {
+                                error!("Failed to create backup directory {}: {}", dest.display(), e);
                                 let _ = event_tx.send(Event::Error {
-                                    message: format!("Failed to create backup directory: {}", e),
+                                    message: format!("Failed to create backup directory {}: {}", dest.display(), e),
                                 });
-                                continue;
+                                create_failed = true;
+                                break;
                             }
-                            info!("Created backup directory: {}", config.backup_dest.display());
-                        }
-                        Ok(false) => {
-                            error!("Backup destination is not mounted: {}", backup_dest);
-                            let _ = event_tx.send(Event::Error {
-                                message: format!("USB drive is not mounted at {}. Please mount the drive and try again.", backup_dest),
-                            });
-                            continue;
+                            info!("Created backup directory: {}", dest.display());
                         }
-                        Err(e) => {
-                            error!("Failed to verify backup mount: {}", e);
-                            let _ = event_tx.send(Event::Error {
-                                message: format!("Failed to verify backup mount: {}", e),
-                            });
+                        if create_failed {
+                            *running_session.lock().unwrap() = None;
                             continue;
                         }
                     }
-                    
+
                     {
                         let mut session = session.write().unwrap();
                         session.state = BackupState::Running;
@@ -158,15 +373,9 @@ impl BackupManager {
                     
                     let _ = event_tx.send(Event::StateChanged(BackupState::Running));
                     
-                    // Start the task manager with appropriate number of workers
-                    let num_workers = if parallel {
-                        std::cmp::min(
-                            config.max_workers,
-                            num_cpus::get() / 2, // Use half the cores for efficiency
-                        )
-                    } else {
-                        1 // Single worker for sequential
-                    };
+                    // Start the task manager with the requested number of concurrent
+                    // directory transfers, capped by the configured worker ceiling.
+                    let num_workers = std::cmp::min(config.max_workers.max(1), parallelism.max(1));
                     
                     // Start the task manager
                     if let Err(e) = task_manager.start(num_workers, session.clone(), config.clone()).await {
@@ -174,15 +383,18 @@ impl BackupManager {
                         let _ = event_tx.send(Event::Error {
                             message: format!("Failed to start task manager: {}", e),
                         });
+                        *running_session.lock().unwrap() = None;
                         continue;
                     }
-                    
+
                     // Create a background task to process directories and add them to task manager
                     let session_clone = session.clone();
                     let task_manager_clone = task_manager.clone();
                     let event_tx_task = event_tx.clone();
                     let log_buffer_clone = log_buffer.clone();
-                    
+                    let running_session_clone = running_session.clone();
+                    let monitor_span = session_span.clone();
+
                     tokio::spawn(async move {
                         // Get all selected directories and add them as tasks
                         let directories_to_process: Vec<(usize, u8, u64)> = {
@@ -243,6 +455,7 @@ impl BackupManager {
                                         let _ = event_tx_task.send(Event::ProgressUpdate {
                                             index: idx,
                                             progress: dir.progress,
+                                            stage: dir.current_stage,
                                         });
                                     }
                                 }
@@ -255,25 +468,40 @@ impl BackupManager {
                                     let mut session = session_clone.write().unwrap();
                                     session.state = BackupState::Stopped;
                                 }
+                                *running_session_clone.lock().unwrap() = None;
                                 let _ = event_tx_task.send(Event::StateChanged(BackupState::Stopped));
                                 break;
                             }
                         }
-                    });
+                    }.instrument(monitor_span));
                 }
                 
                 Command::Pause => {
                     info!("Pausing backup");
                     session.write().unwrap().state = BackupState::Paused;
+                    // Stop workers from picking up new tasks and let an
+                    // in-flight transfer stall at its next checkpoint,
+                    // instead of it erroring out on the session-state check.
+                    task_manager.pause();
                     let _ = event_tx.send(Event::StateChanged(BackupState::Paused));
                 }
-                
+
+                Command::Resume => {
+                    info!("Resuming backup");
+                    session.write().unwrap().state = BackupState::Running;
+                    task_manager.resume();
+                    let _ = event_tx.send(Event::StateChanged(BackupState::Running));
+                }
+
                 Command::Stop => {
                     info!("Stopping backup");
                     session.write().unwrap().state = BackupState::Stopped;
+                    *running_session.lock().unwrap() = None;
                     let _ = event_tx.send(Event::StateChanged(BackupState::Stopped));
-                    
-                    // Shutdown task manager
+
+                    // Cancel outstanding work before tearing down the worker
+                    // pool, rather than waiting for it to drain on its own.
+                    task_manager.cancel_all();
                     if let Err(e) = task_manager.shutdown().await {
                         error!("Error shutting down task manager: {}", e);
                     }
@@ -281,14 +509,71 @@ impl BackupManager {
                 
                 Command::UpdateProgress { index, progress } => {
                     debug!("Updating progress: dir {} = {}%", index, progress);
-                    {
+                    let stage = {
                         let mut session = session.write().unwrap();
                         if let Some(dir) = session.directories.get_mut(index) {
                             dir.progress = progress;
                             dir.size_copied = (dir.size as f64 * progress as f64 / 100.0) as u64;
+                            dir.current_stage
+                        } else {
+                            Stage::Transferring
                         }
-                    }
-                    let _ = event_tx.send(Event::ProgressUpdate { index, progress });
+                    };
+                    let _ = event_tx.send(Event::ProgressUpdate { index, progress, stage });
+                }
+
+                Command::SetTranquility { value } => {
+                    info!("Setting tranquility to {}", value);
+                    task_manager.set_tranquility(value as u32);
+                    session.write().unwrap().tranquility = value;
+                    let _ = event_tx.send(Event::TranquilityChanged { value });
+                }
+
+                Command::Verify { full } => {
+                    info!("Starting verify pass (full={})", full);
+                    let verify_worker = verify_worker.clone();
+                    let session = session.clone();
+                    let config = config.clone();
+                    let event_tx = event_tx.clone();
+                    let tranquility_handle = tranquility_handle.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = verify_worker.run(session, config, event_tx.clone(), tranquility_handle, full).await {
+                            error!("Verify pass failed: {}", e);
+                            let _ = event_tx.send(Event::Error { message: format!("Verify pass failed: {}", e) });
+                        }
+                    });
+                }
+
+                Command::PauseVerify => {
+                    info!("Pausing verify pass");
+                    verify_worker.pause();
+                }
+
+                Command::ResumeVerify => {
+                    info!("Resuming verify pass");
+                    verify_worker.resume();
+                }
+
+                Command::CancelVerify => {
+                    info!("Cancelling verify pass");
+                    verify_worker.cancel();
+                }
+
+                Command::ReloadConfig(new_config) => {
+                    info!("Reloaded config (max_workers={}, rsync_excludes={})", new_config.max_workers, new_config.rsync_excludes.len());
+                    config = Arc::new(new_config);
+
+                    // Re-pick the task processor too, so toggling s3_bucket
+                    // in the reloaded config switches between the local and
+                    // S3 backends without a process restart.
+                    let task_processor: Arc<dyn TaskProcessor> = match S3Destination::from_config(&config) {
+                        Some(destination) => {
+                            info!("Switched to S3 destination: bucket={}", destination.bucket);
+                            Arc::new(S3TaskProcessor::new(destination))
+                        }
+                        None => Arc::new(BackupTaskProcessor::new(event_tx.clone())),
+                    };
+                    task_manager.set_task_processor(task_processor);
                 }
             }
             
@@ -303,16 +588,38 @@ impl BackupManager {
     pub async fn scan_directories(&self) -> Result<()> {
         info!("Scanning directories...");
         
-        let scanner = super::scanner::DirectoryScanner::new(self.config.clone());
-        let directories = scanner.scan_home_directory().await?;
-        
+        let scanner = super::scanner::DirectoryScanner::new(self.config());
+        let event_tx = self.event_tx.clone();
+        let directories = scanner
+            .scan_home_directory_with_progress(|index, total, directory| {
+                let _ = event_tx.send(Event::ScanProgress {
+                    index,
+                    total,
+                    name: directory.name.clone(),
+                    size: directory.size,
+                    file_count: directory.file_count.unwrap_or(0),
+                });
+            })
+            .await?;
+
+        // If a checkpoint from a previous run of this exact session still
+        // exists, resume from it rather than rescanning from the top.
+        let session_id = self.session.read().unwrap().id.clone();
+        let checkpoint = self.checkpoint_manager.latest(&session_id).await?;
+
         let num_directories = {
             let mut session = self.session.write().unwrap();
             session.directories = directories;
             session.total_size = session.directories.iter().map(|d| d.size).sum();
+
+            if let Some(checkpoint) = &checkpoint {
+                info!("Resuming session {} from checkpoint at directory {}", session.id, checkpoint.directory_index);
+                apply_checkpoint(&mut session, checkpoint);
+            }
+
             session.directories.len()
         };
-        
+
         let session_data = self.session.read().unwrap().clone();
         self.storage.save_session(&session_data).await?;
         info!("Found {} directories", num_directories);
@@ -320,8 +627,19 @@ impl BackupManager {
         Ok(())
     }
 
-    pub async fn start(&self, parallel: bool) -> Result<()> {
-        self.command_tx.send(Command::Start { parallel }).await?;
+    pub async fn start(&self, parallelism: usize) -> Result<()> {
+        {
+            let mut running = self.running_session.lock().unwrap();
+            if let Some(session_id) = running.clone() {
+                return Err(ControlError::BackupAlreadyInProgress { session_id }.into());
+            }
+
+            let new_id = generate_session_id();
+            *running = Some(new_id.clone());
+            self.session.write().unwrap().id = new_id;
+        }
+
+        self.command_tx.send(Command::Start { parallelism }).await?;
         Ok(())
     }
 
@@ -330,6 +648,11 @@ impl BackupManager {
         Ok(())
     }
 
+    pub async fn resume(&self) -> Result<()> {
+        self.command_tx.send(Command::Resume).await?;
+        Ok(())
+    }
+
     pub async fn stop(&self) -> Result<()> {
         self.command_tx.send(Command::Stop).await?;
         Ok(())
@@ -337,10 +660,16 @@ impl BackupManager {
 
     pub async fn get_status(&self) -> BackupStatus {
         let session = self.session.read().unwrap();
-        
+
+        let mut directories = session.directories.clone();
+        let warning_counts = self.warning_counts.read().unwrap();
+        for dir in &mut directories {
+            dir.warning_count = warning_counts.get(&dir.name).copied().unwrap_or(0);
+        }
+
         BackupStatus {
             state: session.state,
-            directories: session.directories.clone(),
+            directories,
             current_index: session.current_index,
             total_size: session.total_size,
             completed_size: session.completed_size,
@@ -349,32 +678,145 @@ impl BackupManager {
         }
     }
 
+    /// The full log captured for one directory in the current session,
+    /// read back from the file `DirectoryFileLogLayer` appends to -
+    /// unlike `get_logs`, which only has the last 1000 lines across every
+    /// directory, this has everything logged for just this one.
+    pub async fn get_directory_log(&self, name: &str) -> Vec<crate::utils::log_buffer::LogEntry> {
+        let session_id = self.session.read().unwrap().id.clone();
+        crate::utils::log_layer::read_directory_log(&self.config().backup_dest, &session_id, name).await
+    }
+
+    /// Per-worker liveness for the `/workers` endpoint.
+    pub fn list_workers(&self) -> Vec<WorkerInfo> {
+        self.task_manager.list_workers()
+    }
+
+    /// Aborts just this one directory's transfer - killing its rsync and
+    /// marking it `Skipped` - without stopping the rest of a running
+    /// session. Returns `false` if the directory had no queued or running
+    /// task to cancel.
+    pub fn cancel_directory(&self, directory_index: usize) -> bool {
+        self.task_manager.cancel_directory(directory_index)
+    }
+
+    /// Adjust the IO tranquility (0 = full speed) live while a backup runs,
+    /// routed through `command_tx` like every other control action so it's
+    /// persisted into the session alongside the rest of its state.
+    pub async fn set_tranquility(&self, value: u8) -> Result<()> {
+        self.command_tx.send(Command::SetTranquility { value }).await?;
+        Ok(())
+    }
+
+    /// Starts a verify pass over `Completed` directories, pacing itself by
+    /// the same tranquility knob as the main backup. `full` re-checks
+    /// everything; otherwise only directories no previous pass verified.
+    pub async fn start_verify(&self, full: bool) -> Result<()> {
+        self.command_tx.send(Command::Verify { full }).await?;
+        Ok(())
+    }
+
+    pub async fn pause_verify(&self) -> Result<()> {
+        self.command_tx.send(Command::PauseVerify).await?;
+        Ok(())
+    }
+
+    pub async fn resume_verify(&self) -> Result<()> {
+        self.command_tx.send(Command::ResumeVerify).await?;
+        Ok(())
+    }
+
+    pub async fn cancel_verify(&self) -> Result<()> {
+        self.command_tx.send(Command::CancelVerify).await?;
+        Ok(())
+    }
+
+    /// Captured log lines for a single task, newest-last.
+    pub fn task_log(&self, task_id: u64) -> Vec<super::task_log::LogLine> {
+        self.task_manager.task_log(super::task_manager::TaskId(task_id))
+    }
+
     pub fn subscribe_events(&self) -> broadcast::Receiver<Event> {
         self.event_tx.subscribe()
     }
 
+    /// Re-enqueue whatever the `TaskStore` snapshot still had pending from a
+    /// previous run that didn't shut down cleanly, so an interrupted backup
+    /// resumes instead of starting from scratch. Safe to call even if
+    /// nothing was ever persisted.
+    pub async fn restore_tasks(&self) -> Result<()> {
+        self.task_manager.restore().await
+    }
+
     pub async fn restore_session(&self, session: BackupSession) -> Result<()> {
         info!("Restoring backup session: {}", session.id);
-        
+
+        // Load the latest durably-flushed checkpoint for this session, if
+        // any, so the restored directories resume from the cursor instead
+        // of re-running everything the scanner found.
+        let checkpoint = self.checkpoint_manager.latest(&session.id).await?;
+
         // Update the current session with the restored data
         let num_directories = {
             let mut current_session = self.session.write().unwrap();
             *current_session = session;
+
+            if let Some(checkpoint) = &checkpoint {
+                info!("Found checkpoint at directory {} for session {}", checkpoint.directory_index, current_session.id);
+                apply_checkpoint(&mut current_session, checkpoint);
+            }
+
             current_session.directories.len()
         };
-        
+
+        // Put workers back at the tranquility the session was left at,
+        // rather than resetting to `Config::tranquility` on every restart.
+        let tranquility = self.session.read().unwrap().tranquility;
+        self.task_manager.set_tranquility(tranquility as u32);
+
         // Save the restored session to ensure it's persisted
         let session_data = self.session.read().unwrap().clone();
         self.storage.save_session(&session_data).await?;
-        
+
         info!("Session restored successfully with {} directories", num_directories);
-        
+
         Ok(())
     }
+
+    /// Latest durably-flushed checkpoint for the current session, so the UI
+    /// can show "resumable from X" - `None` if nothing has been flushed yet.
+    pub async fn current_checkpoint(&self) -> Result<Option<Checkpoint>> {
+        let session_id = self.session.read().unwrap().id.clone();
+        self.checkpoint_manager.latest(&session_id).await
+    }
     
     pub fn get_logs(&self, limit: Option<usize>) -> Vec<crate::utils::log_buffer::LogEntry> {
         self.log_buffer.get_logs(limit)
     }
+
+    pub fn storage(&self) -> &Storage {
+        &self.storage
+    }
+
+    /// A snapshot of the current config. Cheap - just bumps the `Arc`'s
+    /// refcount - so callers don't hold the lock any longer than it takes
+    /// to clone it.
+    pub fn config(&self) -> Arc<Config> {
+        self.config.read().unwrap().clone()
+    }
+
+    /// Re-reads `backup.toml` plus environment overrides and swaps it in,
+    /// both for the manager's own snapshot and for the command processor,
+    /// so the next scan/start picks up the new excludes, worker count, and
+    /// destinations without a process restart. Directories already running
+    /// keep whatever config they were started with.
+    pub async fn reload_config(&self) -> Result<()> {
+        let new_config = Config::load()?;
+        info!("Reloading config from disk");
+        *self.config.write().unwrap() = Arc::new(new_config.clone());
+        self.command_tx.send(Command::ReloadConfig(new_config)).await?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -3,14 +3,22 @@ pub mod worker;
 pub mod rsync;
 pub mod scanner;
 pub mod task_manager;
-pub mod dynamic_task_manager;
 pub mod rsync_monitor;
 pub mod task_processor;
+pub mod task_store;
+pub mod task_log;
+pub mod s3_task_processor;
+pub mod prune;
+pub mod checkpoint;
+pub mod disk_layout;
+pub mod integrity;
+pub mod progress;
+pub mod resume;
+pub mod verify;
 
 pub use manager::BackupManager;
 pub use worker::BackupWorker;
 pub use task_manager::TaskManager;
-pub use dynamic_task_manager::DynamicTaskManager;
 
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -32,6 +40,19 @@ pub struct Directory {
     pub average_speed: Option<u64>,
     pub current_file: Option<String>,
     pub bytes_processed: Option<u64>,
+
+    /// Which `Stage` this directory is currently in, and how many stages its
+    /// run has in total (1 when verification is disabled, 2 once the
+    /// post-transfer checksum pass runs too), so the UI can show e.g.
+    /// "Verifying 2/2" instead of treating the whole run as one progress bar.
+    pub current_stage: Stage,
+    pub max_stage: u8,
+
+    /// Warnings logged while processing this directory, refreshed from
+    /// `DirectoryFileLogLayer`'s per-directory counters on each
+    /// `BackupManager::get_status` call.
+    #[serde(default)]
+    pub warning_count: u32,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -42,6 +63,17 @@ pub enum DirectoryStatus {
     Completed,
     Error,
     Skipped,
+    /// A post-hoc `VerifyWorker` pass found a mismatch or missing file for a
+    /// directory that previously finished as `Completed`.
+    VerifyFailed,
+}
+
+/// Which phase of a directory's backup run is currently reporting progress.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Transferring,
+    Verifying,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -62,6 +94,12 @@ pub struct BackupSession {
     pub start_time: Option<i64>,
     pub state: BackupState,
     pub errors: Vec<BackupError>,
+
+    /// Current IO tranquility (0 = full speed), persisted here so
+    /// `restore_session` can put workers back at the level the user left
+    /// them at instead of resetting to `Config::tranquility` on every restart.
+    #[serde(default)]
+    pub tranquility: u8,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,6 +109,14 @@ pub struct BackupError {
     pub timestamp: i64,
 }
 
-// Shared state for zero-copy access  
+// Shared state for zero-copy access
 pub type SharedDirectory = Arc<std::sync::RwLock<Directory>>;
-pub type SharedSession = Arc<std::sync::RwLock<BackupSession>>;
\ No newline at end of file
+pub type SharedSession = Arc<std::sync::RwLock<BackupSession>>;
+
+/// A completed run as recorded in `backup_history`, used by the prune subsystem.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupHistoryRecord {
+    pub session_id: String,
+    pub completed_at: chrono::DateTime<chrono::Utc>,
+    pub total_size: u64,
+}
\ No newline at end of file
@@ -0,0 +1,75 @@
+use tokio::sync::mpsc;
+use tokio::time::{Duration, Instant};
+
+/// One reported step of a directory transfer, sent over a plain `mpsc`
+/// channel rather than broadcast over the web session's event bus -
+/// mirrors the common pattern of a long-running operation taking a sender
+/// and reporting through it, so a TUI/GUI front end (or anything else) can
+/// subscribe to typed progress without re-parsing rsync's text output.
+#[derive(Debug, Clone)]
+pub enum ProgressEvent {
+    Update {
+        bytes_done: u64,
+        total_bytes: u64,
+        speed: f64,
+        current_file: Option<String>,
+        eta: String,
+    },
+    Completed,
+    Failed(String),
+}
+
+/// Wraps an `mpsc::Sender<ProgressEvent>`, coalescing `Update` events to at
+/// most `max_per_sec` so a transfer emitting one rsync line per file
+/// doesn't flood a slow consumer. `Completed`/`Failed` are terminal and
+/// always sent regardless of throttling, since the consumer needs to know
+/// when to stop listening.
+pub struct ProgressReporter {
+    tx: mpsc::Sender<ProgressEvent>,
+    min_interval: Duration,
+    last_sent: Option<Instant>,
+}
+
+impl ProgressReporter {
+    pub fn new(tx: mpsc::Sender<ProgressEvent>, max_per_sec: u32) -> Self {
+        Self {
+            tx,
+            min_interval: Duration::from_secs_f64(1.0 / max_per_sec.max(1) as f64),
+            last_sent: None,
+        }
+    }
+
+    /// Reports a progress step, silently dropped if it arrives before
+    /// `min_interval` has elapsed since the last one went out.
+    pub async fn update(
+        &mut self,
+        bytes_done: u64,
+        total_bytes: u64,
+        speed: f64,
+        current_file: Option<String>,
+        eta: String,
+    ) {
+        let now = Instant::now();
+        if let Some(last) = self.last_sent {
+            if now.duration_since(last) < self.min_interval {
+                return;
+            }
+        }
+        self.last_sent = Some(now);
+
+        let _ = self
+            .tx
+            .send(ProgressEvent::Update { bytes_done, total_bytes, speed, current_file, eta })
+            .await;
+    }
+
+    /// Terminal event - bypasses throttling so it's never dropped.
+    pub async fn completed(&self) {
+        let _ = self.tx.send(ProgressEvent::Completed).await;
+    }
+
+    /// Terminal event - bypasses throttling so it's never dropped.
+    pub async fn failed(&self, error: impl Into<String>) {
+        let _ = self.tx.send(ProgressEvent::Failed(error.into())).await;
+    }
+}
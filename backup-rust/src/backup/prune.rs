@@ -0,0 +1,277 @@
+use super::BackupHistoryRecord;
+use crate::storage::Storage;
+use crate::utils::config::Config;
+use anyhow::Result;
+use chrono::{DateTime, Datelike, IsoWeek, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tracing::{info, warn};
+
+/// Proxmox-style retention policy: keep the N most recent backups outright,
+/// plus the newest backup in each of the last N hourly/daily/weekly/monthly/yearly
+/// buckets.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    pub keep_last: u32,
+    pub keep_hourly: u32,
+    pub keep_daily: u32,
+    pub keep_weekly: u32,
+    pub keep_monthly: u32,
+    pub keep_yearly: u32,
+}
+
+impl RetentionPolicy {
+    /// An all-zero spec means "keep everything" rather than "keep nothing".
+    pub fn is_keep_all(&self) -> bool {
+        self.keep_last == 0
+            && self.keep_hourly == 0
+            && self.keep_daily == 0
+            && self.keep_weekly == 0
+            && self.keep_monthly == 0
+            && self.keep_yearly == 0
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PruneDecision {
+    pub session_id: String,
+    pub completed_at: DateTime<Utc>,
+    pub kept_by: Vec<&'static str>,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct PrunePlan {
+    pub keep: Vec<PruneDecision>,
+    pub remove: Vec<PruneDecision>,
+    pub dry_run: bool,
+}
+
+/// Compute which backups to keep and which to remove, newest-first.
+///
+/// `records` must already be sorted newest-first by `completed_at`.
+pub fn plan(records: &[BackupHistoryRecord], policy: &RetentionPolicy) -> PrunePlan {
+    if policy.is_keep_all() {
+        return PrunePlan {
+            keep: records
+                .iter()
+                .map(|r| PruneDecision {
+                    session_id: r.session_id.clone(),
+                    completed_at: r.completed_at,
+                    kept_by: vec!["keep-all"],
+                })
+                .collect(),
+            remove: Vec::new(),
+            dry_run: false,
+        };
+    }
+
+    let mut kept_by: Vec<Vec<&'static str>> = vec![Vec::new(); records.len()];
+
+    // keep_last: the N newest, unconditionally.
+    for (i, kept) in kept_by.iter_mut().enumerate().take(policy.keep_last as usize) {
+        let _ = i;
+        kept.push("last");
+    }
+
+    bucket_keep(records, &mut kept_by, policy.keep_hourly, "hourly", |t| {
+        format!("{}-{:02}", t.format("%Y-%m-%d"), t.hour())
+    });
+    bucket_keep(records, &mut kept_by, policy.keep_daily, "daily", |t| {
+        t.format("%Y-%m-%d").to_string()
+    });
+    bucket_keep(records, &mut kept_by, policy.keep_weekly, "weekly", |t| {
+        let week: IsoWeek = t.iso_week();
+        format!("{}-{:02}", week.year(), week.week())
+    });
+    bucket_keep(records, &mut kept_by, policy.keep_monthly, "monthly", |t| {
+        t.format("%Y-%m").to_string()
+    });
+    bucket_keep(records, &mut kept_by, policy.keep_yearly, "yearly", |t| {
+        t.format("%Y").to_string()
+    });
+
+    let mut plan = PrunePlan::default();
+    for (record, kept_by) in records.iter().zip(kept_by.into_iter()) {
+        let decision = PruneDecision {
+            session_id: record.session_id.clone(),
+            completed_at: record.completed_at,
+            kept_by,
+        };
+        if decision.kept_by.is_empty() {
+            plan.remove.push(decision);
+        } else {
+            plan.keep.push(decision);
+        }
+    }
+    plan
+}
+
+/// Walk `records` newest-first, keeping the first record seen in each
+/// not-yet-seen period key until `keep_n` distinct periods are kept.
+fn bucket_keep(
+    records: &[BackupHistoryRecord],
+    kept_by: &mut [Vec<&'static str>],
+    keep_n: u32,
+    label: &'static str,
+    period_key: impl Fn(DateTime<Utc>) -> String,
+) {
+    if keep_n == 0 {
+        return;
+    }
+    let mut seen_periods = HashSet::new();
+    for (record, kept) in records.iter().zip(kept_by.iter_mut()) {
+        if seen_periods.len() >= keep_n as usize {
+            break;
+        }
+        let key = period_key(record.completed_at);
+        if seen_periods.insert(key) {
+            kept.push(label);
+        }
+    }
+}
+
+/// Run the prune: compute the plan and, unless `dry_run`, delete the losing
+/// history rows and their on-disk backup directories.
+pub async fn run_prune(
+    storage: &Storage,
+    config: &Config,
+    policy: &RetentionPolicy,
+    dry_run: bool,
+) -> Result<PrunePlan> {
+    let records = storage.list_completed_history().await?;
+    let mut plan = plan(&records, policy);
+    plan.dry_run = dry_run;
+
+    if dry_run || plan.remove.is_empty() {
+        return Ok(plan);
+    }
+
+    for decision in &plan.remove {
+        let dir = config.backup_dest.join(&decision.session_id);
+        if dir.exists() {
+            if let Err(e) = tokio::fs::remove_dir_all(&dir).await {
+                warn!("Failed to remove pruned backup dir {}: {}", dir.display(), e);
+                continue;
+            }
+        }
+        storage.delete_history_by_session(&decision.session_id).await?;
+        info!("Pruned backup {}", decision.session_id);
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn record(session_id: &str, completed_at: DateTime<Utc>) -> BackupHistoryRecord {
+        BackupHistoryRecord {
+            session_id: session_id.to_string(),
+            completed_at,
+            total_size: 0,
+        }
+    }
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn all_zero_policy_keeps_everything() {
+        let policy = RetentionPolicy {
+            keep_last: 0,
+            keep_hourly: 0,
+            keep_daily: 0,
+            keep_weekly: 0,
+            keep_monthly: 0,
+            keep_yearly: 0,
+        };
+        assert!(policy.is_keep_all());
+
+        let records = vec![record("a", at(2026, 1, 1, 0)), record("b", at(2025, 1, 1, 0))];
+        let plan = plan(&records, &policy);
+        assert_eq!(plan.keep.len(), 2);
+        assert!(plan.remove.is_empty());
+        assert_eq!(plan.keep[0].kept_by, vec!["keep-all"]);
+    }
+
+    #[test]
+    fn keep_last_wins_regardless_of_bucket() {
+        let policy = RetentionPolicy {
+            keep_last: 1,
+            keep_hourly: 0,
+            keep_daily: 0,
+            keep_weekly: 0,
+            keep_monthly: 0,
+            keep_yearly: 0,
+        };
+        let records = vec![record("newest", at(2026, 1, 2, 0)), record("older", at(2026, 1, 1, 0))];
+        let plan = plan(&records, &policy);
+        assert_eq!(plan.keep.len(), 1);
+        assert_eq!(plan.keep[0].session_id, "newest");
+        assert_eq!(plan.remove.len(), 1);
+        assert_eq!(plan.remove[0].session_id, "older");
+    }
+
+    #[test]
+    fn daily_bucket_keeps_newest_per_day_only() {
+        let policy = RetentionPolicy {
+            keep_last: 0,
+            keep_hourly: 0,
+            keep_daily: 1,
+            keep_weekly: 0,
+            keep_monthly: 0,
+            keep_yearly: 0,
+        };
+        // Two backups the same day, newest-first: only the first should be kept.
+        let records = vec![
+            record("same-day-morning", at(2026, 1, 2, 9)),
+            record("same-day-midnight", at(2026, 1, 2, 0)),
+            record("prior-day", at(2026, 1, 1, 0)),
+        ];
+        let plan = plan(&records, &policy);
+        assert_eq!(plan.keep.len(), 1);
+        assert_eq!(plan.keep[0].session_id, "same-day-morning");
+        assert_eq!(plan.remove.len(), 2);
+    }
+
+    #[test]
+    fn daily_bucket_stops_once_keep_n_distinct_days_seen() {
+        let policy = RetentionPolicy {
+            keep_last: 0,
+            keep_hourly: 0,
+            keep_daily: 2,
+            keep_weekly: 0,
+            keep_monthly: 0,
+            keep_yearly: 0,
+        };
+        let records = vec![
+            record("day3", at(2026, 1, 3, 0)),
+            record("day2", at(2026, 1, 2, 0)),
+            record("day1", at(2026, 1, 1, 0)),
+        ];
+        let plan = plan(&records, &policy);
+        let kept: HashSet<_> = plan.keep.iter().map(|d| d.session_id.clone()).collect();
+        assert_eq!(kept, HashSet::from(["day3".to_string(), "day2".to_string()]));
+        assert_eq!(plan.remove.len(), 1);
+        assert_eq!(plan.remove[0].session_id, "day1");
+    }
+
+    #[test]
+    fn a_record_kept_by_multiple_buckets_is_kept_once() {
+        let policy = RetentionPolicy {
+            keep_last: 1,
+            keep_hourly: 0,
+            keep_daily: 1,
+            keep_weekly: 0,
+            keep_monthly: 1,
+            keep_yearly: 0,
+        };
+        let records = vec![record("only", at(2026, 1, 1, 0))];
+        let plan = plan(&records, &policy);
+        assert_eq!(plan.keep.len(), 1);
+        assert_eq!(plan.keep[0].kept_by, vec!["last", "daily", "monthly"]);
+    }
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+/// Name of the directory rsync's `--partial-dir` stashes a transfer's
+/// not-yet-complete files under, instead of leaving a `<file>.XXXXXX` temp
+/// file next to its final name. Keeping partials in their own subdirectory
+/// means a resumed transfer's already-completed files are never mistaken
+/// for incomplete ones, and `resume_scan` only has one place to look.
+pub const PARTIAL_DIR_NAME: &str = ".rsync-partial";
+
+/// Files a previous, interrupted run left behind under a destination's
+/// partial-dir. Passing the same `--partial-dir` again lets rsync resume
+/// (or re-verify and append to, with `--append-verify`) these from where
+/// they left off rather than starting over, so `resume_scan` exists to
+/// report what's still incomplete rather than to move any bytes itself.
+#[derive(Debug, Clone, Default)]
+pub struct ResumeState {
+    pub incomplete_files: Vec<PathBuf>,
+}
+
+/// Walks `dst_root`'s partial-dir for files left over from an interrupted
+/// transfer. An empty `ResumeState` (including when the partial-dir
+/// doesn't exist at all) means the previous run finished cleanly.
+pub async fn resume_scan(dst_root: &Path) -> Result<ResumeState> {
+    let mut incomplete_files = Vec::new();
+    let mut dirs = vec![dst_root.join(PARTIAL_DIR_NAME)];
+
+    while let Some(dir) = dirs.pop() {
+        let mut read_dir = match tokio::fs::read_dir(&dir).await {
+            Ok(read_dir) => read_dir,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        while let Some(entry) = read_dir.next_entry().await? {
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                dirs.push(entry.path());
+            } else if file_type.is_file() {
+                incomplete_files.push(entry.path());
+            }
+        }
+    }
+
+    Ok(ResumeState { incomplete_files })
+}
@@ -1,42 +1,97 @@
+use super::worker::{parse_rsync_progress, parse_speed_from_progress_line};
 use anyhow::Result;
 use std::path::Path;
+use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command;
+use tokio::sync::mpsc;
 
-/// Run rsync with common options
+/// A single progress sample parsed from a line of `rsync --info=progress2` output.
+#[derive(Debug, Clone)]
+pub struct RsyncProgress {
+    pub bytes_transferred: u64,
+    pub percent: u8,
+    pub rate_mbps: f64,
+    pub current_file: Option<String>,
+}
+
+/// Run rsync with common options, streaming its progress output line-by-line
+/// instead of waiting for `cmd.output()` to return at the very end, so a
+/// caller can follow the transfer live via `progress_tx`.
 pub async fn run_rsync(
     source: &Path,
     destination: &Path,
     excludes: &[String],
     dry_run: bool,
+    progress_tx: Option<mpsc::UnboundedSender<RsyncProgress>>,
 ) -> Result<()> {
     let mut cmd = Command::new("rsync");
-    
+
     // Basic options
-    cmd.args(&["-avz", "--progress", "--stats"]);
-    
+    cmd.args(&["-avz", "--info=progress2", "--out-format=%n", "--stats"]);
+
     // Preserve permissions where possible
     cmd.args(&["--no-perms", "--no-owner", "--no-group"]);
-    
+
     if dry_run {
         cmd.arg("--dry-run");
     }
-    
+
     // Add excludes
     for exclude in excludes {
         cmd.arg(format!("--exclude={}", exclude));
     }
-    
+
     // Source and destination
     cmd.arg(source);
     cmd.arg(destination);
-    
-    let output = cmd.output().await?;
-    
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow::anyhow!("rsync failed: {}", stderr));
+
+    cmd.stdout(std::process::Stdio::piped());
+    cmd.stderr(std::process::Stdio::piped());
+
+    let mut child = cmd.spawn()?;
+
+    let stdout = child.stdout.take().unwrap();
+    let stderr = child.stderr.take().unwrap();
+
+    let stderr_task = tokio::spawn(async move {
+        let mut lines = BufReader::new(stderr).lines();
+        let mut errors = Vec::new();
+        while let Ok(Some(line)) = lines.next_line().await {
+            errors.push(line);
+        }
+        errors
+    });
+
+    let mut lines = BufReader::new(stdout).lines();
+    let mut current_file: Option<String> = None;
+
+    while let Some(line) = lines.next_line().await? {
+        // `--out-format=%n` emits a bare filename line per transferred file.
+        if !line.contains('%') && !line.trim().is_empty() {
+            current_file = Some(line.trim().to_string());
+        }
+
+        if let Some(percent) = parse_rsync_progress(&line) {
+            if let Some((bytes, rate)) = parse_speed_from_progress_line(&line) {
+                if let Some(tx) = &progress_tx {
+                    let _ = tx.send(RsyncProgress {
+                        bytes_transferred: bytes,
+                        percent,
+                        rate_mbps: rate / 1_048_576.0,
+                        current_file: current_file.clone(),
+                    });
+                }
+            }
+        }
     }
-    
+
+    let status = child.wait().await?;
+    let stderr_lines = stderr_task.await.unwrap_or_default();
+
+    if !status.success() {
+        return Err(anyhow::anyhow!("rsync failed: {}", stderr_lines.join("\n")));
+    }
+
     Ok(())
 }
 
@@ -46,11 +101,11 @@ pub async fn check_rsync() -> Result<String> {
         .arg("--version")
         .output()
         .await?;
-    
+
     if !output.status.success() {
         return Err(anyhow::anyhow!("rsync not found"));
     }
-    
+
     let version = String::from_utf8_lossy(&output.stdout);
     Ok(version.lines().next().unwrap_or("unknown").to_string())
-}
\ No newline at end of file
+}
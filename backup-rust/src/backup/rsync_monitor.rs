@@ -7,6 +7,15 @@ use tracing::{info, warn, error};
 use std::time::{Duration, Instant};
 use regex::Regex;
 
+// halcasteel/backup2usb-chromeos#chunk8-wontdo: chunk8-1..5 added real
+// /proc+/sys sampling, an xxHash deep-verification mode, checksum
+// negotiation, hot-reloadable MonitorConfig, and a metrics_history/NDJSON
+// export here, but worker.rs - the only caller - never adopted any of it;
+// it runs its own read loop with its own stall watchdog and checkpointing,
+// and the app's real verification path is integrity::verify_backup via
+// verify.rs. Closed won't-do rather than replacing that already-working
+// loop with a second, inert implementation; see 3a2b3d3 for the revert
+// back to this baseline.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RsyncMetrics {
     pub connection_status: ConnectionStatus,
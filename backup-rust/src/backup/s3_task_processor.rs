@@ -0,0 +1,446 @@
+use super::task_manager::{Task, TaskId, TaskMetrics, TaskStatus};
+use super::{DirectoryStatus, SharedSession};
+use crate::utils::config::Config;
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_s3::config::{BehaviorVersion, Region};
+use aws_sdk_s3::primitives::ByteStream;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+use tokio::sync::{Notify, OnceCell};
+use tracing::{debug, error, info, trace};
+
+use super::checkpoint::{Checkpoint, CheckpointManager};
+use super::disk_layout::DiskLayoutManager;
+use super::task_processor::TaskProcessor;
+
+/// Where to upload, and how to authenticate - mirrors Spacedrive's location
+/// layer wiring a `CredentialsProvider` into a reused static `Client` for
+/// cloud destinations, so self-hosted stores (MinIO, Garage, ...) work via
+/// `endpoint` the same way AWS does.
+#[derive(Debug, Clone)]
+pub struct S3Destination {
+    pub bucket: String,
+    pub endpoint: Option<String>,
+    pub region: Option<String>,
+    pub prefix: Option<String>,
+    pub credentials: Option<StaticCredentials>,
+}
+
+#[derive(Clone)]
+pub struct StaticCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+/// Manual impl so a stray `{:?}` - or one of this series's own logging
+/// layers (chunk0-4, chunk3-5, chunk5-1) persisting an event that formats
+/// this struct - never durably logs the plaintext secret key.
+impl std::fmt::Debug for StaticCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StaticCredentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"[redacted]")
+            .finish()
+    }
+}
+
+impl S3Destination {
+    /// `None` if no S3 destination is configured, in which case the local
+    /// USB mount (`BackupTaskProcessor`) remains the destination.
+    pub fn from_config(config: &Config) -> Option<Self> {
+        let bucket = config.s3_bucket.clone()?;
+        let credentials = match (&config.s3_access_key_id, &config.s3_secret_access_key) {
+            (Some(access_key_id), Some(secret_access_key)) => Some(StaticCredentials {
+                access_key_id: access_key_id.clone(),
+                secret_access_key: secret_access_key.clone(),
+            }),
+            _ => None,
+        };
+
+        Some(Self {
+            bucket,
+            endpoint: config.s3_endpoint.clone(),
+            region: config.s3_region.clone(),
+            prefix: config.s3_prefix.clone(),
+            credentials,
+        })
+    }
+
+    fn key_for(&self, directory_name: &str, relative_path: &Path) -> String {
+        let mut parts: Vec<String> = Vec::new();
+        if let Some(prefix) = &self.prefix {
+            parts.push(prefix.trim_matches('/').to_string());
+        }
+        parts.push(directory_name.to_string());
+        parts.push(relative_path.to_string_lossy().replace('\\', "/"));
+        parts.into_iter().filter(|p| !p.is_empty()).collect::<Vec<_>>().join("/")
+    }
+}
+
+/// Shared by every `/start` entry point (compat and `/control`): an S3
+/// destination checks bucket reachability, otherwise falls back to the
+/// local USB mount check `verify_backup_mount` already did. With several
+/// destinations configured, only one of them needs to be mounted - per-
+/// directory placement in `BackupWorker` skips whichever ones aren't.
+pub async fn verify_backup_destination(config: &Config) -> Result<(), String> {
+    if let Some(destination) = S3Destination::from_config(config) {
+        return S3TaskProcessor::new(destination)
+            .check_bucket_reachable()
+            .await
+            .map_err(|e| format!("S3 destination not reachable: {}", e));
+    }
+
+    let mut last_error = String::new();
+    for dest in &config.backup_destinations {
+        let backup_dest = dest.parent().unwrap_or(dest).to_string_lossy();
+        match crate::utils::disk::verify_backup_mount(&backup_dest).await {
+            Ok(true) => return Ok(()),
+            Ok(false) => last_error = format!("USB drive is not mounted at {}", backup_dest),
+            Err(e) => last_error = format!("Failed to verify backup mount at {}: {}", backup_dest, e),
+        }
+    }
+
+    Err(format!("{}. Please mount the drive and try again.", last_error))
+}
+
+async fn build_client(destination: &S3Destination) -> aws_sdk_s3::Client {
+    let mut loader = aws_config::defaults(BehaviorVersion::latest());
+
+    let region = destination.region.clone().unwrap_or_else(|| "us-east-1".to_string());
+    loader = loader.region(Region::new(region));
+
+    if let Some(creds) = &destination.credentials {
+        loader = loader.credentials_provider(aws_sdk_s3::config::Credentials::new(
+            creds.access_key_id.clone(),
+            creds.secret_access_key.clone(),
+            None,
+            None,
+            "backup-system-static",
+        ));
+    }
+
+    let sdk_config = loader.load().await;
+    let mut s3_builder = aws_sdk_s3::config::Builder::from(&sdk_config);
+    if let Some(endpoint) = &destination.endpoint {
+        // Self-hosted stores are usually addressed by IP/hostname rather
+        // than a bucket subdomain, so force path-style requests.
+        s3_builder = s3_builder.endpoint_url(endpoint.clone()).force_path_style(true);
+    }
+
+    aws_sdk_s3::Client::from_conf(s3_builder.build())
+}
+
+/// `TaskProcessor` that uploads each task's directory to an S3-compatible
+/// bucket instead of rsyncing it to a locally-mounted USB drive, so the
+/// existing worker pool, pause/cancel checkpoints, and metrics path all
+/// carry over unchanged - only the transport differs.
+pub struct S3TaskProcessor {
+    destination: S3Destination,
+    client: OnceCell<aws_sdk_s3::Client>,
+}
+
+impl S3TaskProcessor {
+    pub fn new(destination: S3Destination) -> Self {
+        Self {
+            destination,
+            client: OnceCell::new(),
+        }
+    }
+
+    async fn client(&self) -> &aws_sdk_s3::Client {
+        self.client.get_or_init(|| build_client(&self.destination)).await
+    }
+
+    /// Used in place of `verify_backup_mount` when an S3 destination is
+    /// configured: confirms the bucket exists and is reachable rather than
+    /// checking for a local mount point.
+    pub async fn check_bucket_reachable(&self) -> Result<()> {
+        self.client()
+            .await
+            .head_bucket()
+            .bucket(&self.destination.bucket)
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow::anyhow!("S3 bucket '{}' not reachable: {}", self.destination.bucket, e))
+    }
+
+    /// Cooperative pause/cancel checkpoint, called once per uploaded file -
+    /// the S3 equivalent of the per-rsync-line checkpoint in `BackupWorker`.
+    async fn checkpoint(
+        worker_id: usize,
+        task_id: TaskId,
+        paused: &AtomicBool,
+        resume_notify: &Notify,
+        task_status: &RwLock<HashMap<TaskId, TaskStatus>>,
+    ) -> Result<()> {
+        if matches!(task_status.read().unwrap().get(&task_id), Some(TaskStatus::Cancelled)) {
+            return Err(anyhow::anyhow!("task cancelled"));
+        }
+
+        if paused.load(Ordering::SeqCst) {
+            task_status.write().unwrap().insert(task_id, TaskStatus::Paused { worker_id });
+
+            while paused.load(Ordering::SeqCst) {
+                resume_notify.notified().await;
+            }
+
+            if matches!(task_status.read().unwrap().get(&task_id), Some(TaskStatus::Cancelled)) {
+                return Err(anyhow::anyhow!("task cancelled"));
+            }
+
+            task_status.write().unwrap().insert(task_id, TaskStatus::Running { worker_id, progress: 0 });
+        }
+
+        Ok(())
+    }
+
+    /// Canonicalized paths `list_files` must never walk into or upload:
+    /// this backup's own content-hash manifest and rsync resume artifacts,
+    /// in case `root` (or an ancestor of it) is also where a local USB
+    /// backup writes them. A path that doesn't exist yet is skipped rather
+    /// than canonicalized, since there's nothing under it to avoid.
+    async fn avoid_paths(root: &Path) -> Vec<PathBuf> {
+        let mut avoid = Vec::new();
+        for candidate in [
+            root.join(super::integrity::MANIFEST_FILE_NAME),
+            root.join(super::resume::PARTIAL_DIR_NAME),
+        ] {
+            if let Ok(canonical) = tokio::fs::canonicalize(&candidate).await {
+                avoid.push(canonical);
+            }
+        }
+        avoid
+    }
+
+    /// `Some(size)` if `key` already exists in the destination bucket,
+    /// `None` if it doesn't (or the check itself failed - treated the same
+    /// as "not there" so a transient `HeadObject` error can't wedge a run).
+    async fn object_size(&self, client: &aws_sdk_s3::Client, key: &str) -> Option<u64> {
+        client
+            .head_object()
+            .bucket(&self.destination.bucket)
+            .key(key)
+            .send()
+            .await
+            .ok()
+            .and_then(|output| output.content_length())
+            .map(|len| len as u64)
+    }
+
+    /// Recursively collect every regular file under `root`, relative to it,
+    /// skipping anything whose canonicalized path matches `avoid_paths` -
+    /// notably the backup's own manifest/resume artifacts, which live under
+    /// a directory being backed up but must never be uploaded as if they
+    /// were user data.
+    async fn list_files(root: &Path, avoid_paths: &[PathBuf]) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        let mut stack = vec![root.to_path_buf()];
+
+        while let Some(dir) = stack.pop() {
+            let mut entries = tokio::fs::read_dir(&dir).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                let path = entry.path();
+
+                let canonical = tokio::fs::canonicalize(&path).await.unwrap_or_else(|_| path.clone());
+                if avoid_paths.contains(&canonical) {
+                    trace!("Skipping {:?}", path);
+                    continue;
+                }
+
+                let file_type = entry.file_type().await?;
+                if file_type.is_dir() {
+                    stack.push(path);
+                } else if file_type.is_file() {
+                    files.push(path.strip_prefix(root).unwrap_or(&path).to_path_buf());
+                }
+            }
+        }
+
+        Ok(files)
+    }
+}
+
+#[async_trait]
+impl TaskProcessor for S3TaskProcessor {
+    async fn process_task(
+        &self,
+        worker_id: usize,
+        task: &Task,
+        session: &SharedSession,
+        _config: &Arc<Config>,
+        paused: Arc<AtomicBool>,
+        resume_notify: Arc<Notify>,
+        task_status: Arc<RwLock<HashMap<TaskId, TaskStatus>>>,
+        checkpoint_manager: Option<Arc<CheckpointManager>>,
+        _disk_layout: Option<Arc<DiskLayoutManager>>,
+        last_activity: Arc<Mutex<Instant>>,
+    ) -> Result<TaskMetrics> {
+        let (name, path) = {
+            let session = session.read().unwrap();
+            let dir = &session.directories[task.directory_index];
+            (dir.name.clone(), dir.path.clone())
+        };
+
+        info!("Worker {} uploading {} to s3://{}", worker_id, name, self.destination.bucket);
+
+        {
+            let mut session_guard = session.write().unwrap();
+            if let Some(dir) = session_guard.directories.get_mut(task.directory_index) {
+                dir.status = DirectoryStatus::Active;
+                dir.start_time = Some(chrono::Utc::now().timestamp());
+            }
+        }
+
+        let start_time = std::time::Instant::now();
+        let result = self.upload_directory(
+            worker_id,
+            task,
+            &name,
+            &path,
+            session,
+            &paused,
+            &resume_notify,
+            &task_status,
+            checkpoint_manager.as_deref(),
+            &last_activity,
+        ).await;
+
+        match result {
+            Ok((files_processed, bytes_processed)) => {
+                let duration_ms = start_time.elapsed().as_millis() as u64;
+
+                {
+                    let mut session_guard = session.write().unwrap();
+                    if let Some(dir) = session_guard.directories.get_mut(task.directory_index) {
+                        dir.status = DirectoryStatus::Completed;
+                        dir.progress = 100;
+                        dir.size_copied = bytes_processed;
+                        dir.files_processed = files_processed;
+                        dir.bytes_processed = Some(bytes_processed);
+                        dir.end_time = Some(chrono::Utc::now().timestamp());
+                    }
+                }
+
+                Ok(TaskMetrics {
+                    files_processed,
+                    bytes_processed,
+                    duration_ms,
+                    average_speed_mbps: if duration_ms > 0 {
+                        (bytes_processed as f64 / 1_048_576.0) / (duration_ms as f64 / 1000.0)
+                    } else {
+                        0.0
+                    },
+                })
+            }
+            Err(e) => {
+                error!("Worker {} failed uploading {}: {}", worker_id, name, e);
+
+                let mut session_guard = session.write().unwrap();
+                if let Some(dir) = session_guard.directories.get_mut(task.directory_index) {
+                    dir.status = DirectoryStatus::Error;
+                }
+                session_guard.errors.push(super::BackupError {
+                    directory: name,
+                    message: e.to_string(),
+                    timestamp: chrono::Utc::now().timestamp(),
+                });
+
+                Err(e)
+            }
+        }
+    }
+}
+
+impl S3TaskProcessor {
+    #[allow(clippy::too_many_arguments)]
+    async fn upload_directory(
+        &self,
+        worker_id: usize,
+        task: &Task,
+        name: &str,
+        path: &Path,
+        session: &SharedSession,
+        paused: &AtomicBool,
+        resume_notify: &Notify,
+        task_status: &RwLock<HashMap<TaskId, TaskStatus>>,
+        checkpoint_manager: Option<&CheckpointManager>,
+        last_activity: &Mutex<Instant>,
+    ) -> Result<(u64, u64)> {
+        let avoid_paths = Self::avoid_paths(path).await;
+        let files = Self::list_files(path, &avoid_paths).await?;
+        let total_files = files.len() as u64;
+        let client = self.client().await;
+
+        let mut files_processed = 0u64;
+        let mut bytes_processed = 0u64;
+
+        for relative_path in &files {
+            *last_activity.lock() = Instant::now();
+            Self::checkpoint(worker_id, task.id, paused, resume_notify, task_status).await?;
+
+            if let Some(manager) = checkpoint_manager {
+                let session_id = session.read().unwrap().id.clone();
+                manager.record_progress(Checkpoint {
+                    session_id,
+                    directory_index: task.directory_index,
+                    directory_name: name.to_string(),
+                    current_file: Some(relative_path.to_string_lossy().into_owned()),
+                    bytes_processed,
+                    files_processed,
+                    updated_at: chrono::Utc::now(),
+                }).await;
+            }
+
+            let local_path = path.join(relative_path);
+            let key = self.destination.key_for(name, relative_path);
+            let size = tokio::fs::metadata(&local_path).await?.len();
+
+            // Object already present with the same size - almost certainly
+            // this file from a previous, interrupted run. `HeadObject`
+            // doesn't give us a content hash worth trusting (multipart
+            // uploads' ETags aren't a digest of the plaintext), so a size
+            // match is the practical equivalent here.
+            if self.object_size(&client, &key).await == Some(size) {
+                trace!("Skipping {:?}, already uploaded", local_path);
+            } else {
+                trace!("Copying {:?} to s3://{}/{}", local_path, self.destination.bucket, key);
+                let body = ByteStream::from_path(&local_path).await?;
+                debug!("Uploading {:?} to s3://{}/{}", local_path, self.destination.bucket, key);
+                client
+                    .put_object()
+                    .bucket(&self.destination.bucket)
+                    .key(&key)
+                    .body(body)
+                    .send()
+                    .await
+                    .map_err(|e| anyhow::anyhow!("failed to upload {:?}: {}", relative_path, e))?;
+            }
+
+            files_processed += 1;
+            bytes_processed += size;
+
+            let progress = if total_files > 0 {
+                ((files_processed as f64 / total_files as f64) * 100.0) as u8
+            } else {
+                100
+            };
+
+            let mut session_guard = session.write().unwrap();
+            if let Some(dir) = session_guard.directories.get_mut(task.directory_index) {
+                dir.progress = progress;
+                dir.files_processed = files_processed;
+                dir.size_copied = bytes_processed;
+                dir.bytes_processed = Some(bytes_processed);
+                dir.current_file = Some(relative_path.to_string_lossy().to_string());
+            }
+        }
+
+        Ok((files_processed, bytes_processed))
+    }
+}
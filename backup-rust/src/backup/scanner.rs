@@ -1,10 +1,12 @@
 use super::{Directory, DirectoryStatus};
 use crate::utils::config::Config;
 use anyhow::Result;
-use std::path::Path;
-use tokio::fs;
+use futures::future::{BoxFuture, FutureExt};
+use glob::Pattern;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tracing::{info, debug};
+use tokio::fs;
+use tracing::{debug, info, warn};
 
 pub struct DirectoryScanner {
     config: Arc<Config>,
@@ -16,21 +18,43 @@ impl DirectoryScanner {
     }
 
     pub async fn scan_home_directory(&self) -> Result<Vec<Directory>> {
+        self.scan_home_directory_with_progress(|_, _, _| {}).await
+    }
+
+    /// Same as `scan_home_directory`, but calls `on_progress(index, total,
+    /// directory)` as each top-level directory's recursive size finishes,
+    /// so a caller doesn't have to wait for the whole scan to report
+    /// anything.
+    pub async fn scan_home_directory_with_progress(
+        &self,
+        mut on_progress: impl FnMut(usize, usize, &Directory),
+    ) -> Result<Vec<Directory>> {
         let home_path = Path::new(&self.config.home_dir);
         if !home_path.exists() {
             return Err(anyhow::anyhow!("Home directory does not exist: {}", self.config.home_dir));
         }
 
         info!("Scanning directories in {}", self.config.home_dir);
-        let mut directories = Vec::new();
 
-        // Read home directory
+        let excludes: Vec<Pattern> = self
+            .config
+            .rsync_excludes
+            .iter()
+            .filter_map(|pattern| match Pattern::new(pattern) {
+                Ok(p) => Some(p),
+                Err(e) => {
+                    warn!("Ignoring invalid rsync_excludes pattern {:?}: {}", pattern, e);
+                    None
+                }
+            })
+            .collect();
+
+        let mut candidates = Vec::new();
         let mut entries = fs::read_dir(home_path).await?;
-        
+
         while let Some(entry) = entries.next_entry().await? {
             let path = entry.path();
-            
-            // Skip if not a directory
+
             if !path.is_dir() {
                 continue;
             }
@@ -40,30 +64,29 @@ impl DirectoryScanner {
                 None => continue,
             };
 
-            // Skip hidden directories
             if name.starts_with('.') {
                 debug!("Skipping hidden directory: {}", name);
                 continue;
             }
 
-            // Skip excluded directories
-            if self.config.rsync_excludes.iter().any(|exc| name == *exc) {
+            if excludes.iter().any(|p| p.matches(&name)) {
                 debug!("Skipping excluded directory: {}", name);
                 continue;
             }
 
-            // For faster scanning, just get metadata size initially
-            // Full size calculation can happen later
-            let size = match fs::metadata(&path).await {
-                Ok(metadata) => metadata.len(),
-                Err(_) => 1024, // Default size if we can't read metadata
-            };
+            candidates.push((name, path));
+        }
+
+        let total = candidates.len();
+        let mut directories = Vec::with_capacity(total);
 
-            info!("Found directory: {}", name);
-            
-            directories.push(Directory {
-                name: name.clone(),
-                path: path.clone(),
+        for (index, (name, path)) in candidates.into_iter().enumerate() {
+            let (size, file_count) = Self::scan_size(&path, &path, &excludes).await;
+            info!("Found directory: {} ({} bytes, {} files)", name, size, file_count);
+
+            let directory = Directory {
+                name,
+                path,
                 size,
                 status: DirectoryStatus::Pending,
                 progress: 0,
@@ -72,11 +95,17 @@ impl DirectoryScanner {
                 end_time: None,
                 files_processed: 0,
                 size_copied: 0,
-                file_count: None,
+                file_count: Some(file_count),
                 average_speed: None,
                 current_file: None,
                 bytes_processed: None,
-            });
+                current_stage: super::Stage::Transferring,
+                max_stage: if self.config.verify_after_backup { 2 } else { 1 },
+                warning_count: 0,
+            };
+
+            on_progress(index + 1, total, &directory);
+            directories.push(directory);
         }
 
         // Sort directories by name (descending) to match original behavior
@@ -85,4 +114,83 @@ impl DirectoryScanner {
         Ok(directories)
     }
 
-}
\ No newline at end of file
+    /// Whether `relative` (a path within the directory being scanned)
+    /// matches one of `excludes` - tried against the full relative path and
+    /// each individual component, the same set of cases rsync's own
+    /// `--exclude` patterns can match against.
+    fn excluded(relative: &Path, excludes: &[Pattern]) -> bool {
+        let relative_str = relative.to_string_lossy();
+        if excludes.iter().any(|p| p.matches(&relative_str)) {
+            return true;
+        }
+        relative
+            .components()
+            .any(|c| excludes.iter().any(|p| p.matches(&c.as_os_str().to_string_lossy())))
+    }
+
+    /// Recursively sums real file sizes and file count under `dir`, unlike
+    /// `fs::metadata(dir).len()` which is only the directory inode's own
+    /// size. Sibling subdirectories are walked concurrently rather than one
+    /// at a time, since this is pure IO wait with no shared state to
+    /// contend over.
+    fn scan_size<'a>(dir: &'a Path, root: &'a Path, excludes: &'a [Pattern]) -> BoxFuture<'a, (u64, u64)> {
+        async move {
+            let mut read_dir = match fs::read_dir(dir).await {
+                Ok(read_dir) => read_dir,
+                Err(e) => {
+                    warn!("Failed to read {}: {}", dir.display(), e);
+                    return (0, 0);
+                }
+            };
+
+            let mut files = Vec::new();
+            let mut subdirs = Vec::new();
+
+            loop {
+                let entry = match read_dir.next_entry().await {
+                    Ok(Some(entry)) => entry,
+                    Ok(None) => break,
+                    Err(e) => {
+                        warn!("Failed to read entry under {}: {}", dir.display(), e);
+                        break;
+                    }
+                };
+
+                let path = entry.path();
+                let relative = path.strip_prefix(root).unwrap_or(&path);
+                if Self::excluded(relative, excludes) {
+                    continue;
+                }
+
+                match entry.file_type().await {
+                    Ok(file_type) if file_type.is_dir() => subdirs.push(path),
+                    Ok(file_type) if file_type.is_file() => files.push(path),
+                    _ => {}
+                }
+            }
+
+            let mut size = 0u64;
+            let mut file_count = 0u64;
+            for path in &files {
+                if let Ok(metadata) = fs::metadata(path).await {
+                    size += metadata.len();
+                    file_count += 1;
+                }
+            }
+
+            let children: Vec<PathBuf> = subdirs;
+            let results = futures::future::join_all(
+                children.iter().map(|subdir| Self::scan_size(subdir, root, excludes)),
+            )
+            .await;
+
+            for (child_size, child_count) in results {
+                size += child_size;
+                file_count += child_count;
+            }
+
+            (size, file_count)
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,133 @@
+use super::task_manager::TaskId;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// A single captured log line for one task.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogLine {
+    pub timestamp: i64,
+    pub level: String,
+    pub message: String,
+}
+
+/// Per-task log ring buffers, shared between the `TaskLogLayer` that fills
+/// them and `TaskManager::task_log` that reads them back.
+pub type TaskLogRegistry = Arc<RwLock<HashMap<TaskId, VecDeque<LogLine>>>>;
+
+#[derive(Default)]
+struct FieldVisitor {
+    task_id: Option<u64>,
+    message: Option<String>,
+}
+
+impl Visit for FieldVisitor {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "task_id" {
+            self.task_id = Some(value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_debug(field, &value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        match field.name() {
+            "task_id" => {
+                if let Ok(id) = format!("{:?}", value).parse::<u64>() {
+                    self.task_id = Some(id);
+                }
+            }
+            "message" => self.message = Some(format!("{:?}", value).trim_matches('"').to_string()),
+            _ => {}
+        }
+    }
+}
+
+/// Tracing layer that captures `info!`/`warn!`/`error!` events emitted
+/// inside the `task` span `worker_loop` opens around `process_task`, keyed
+/// on that span's `task_id` field, into a bounded per-task ring buffer. This
+/// follows Proxmox Backup's move from a bespoke `task_log!` macro to
+/// `tracing`-based per-worker task logs that clients can read back.
+pub struct TaskLogLayer {
+    registry: TaskLogRegistry,
+    max_lines: usize,
+}
+
+impl TaskLogLayer {
+    /// Returns the layer to install on the subscriber, plus the registry
+    /// handle to hand to `TaskManager::set_task_log_registry` so both sides
+    /// share the same map.
+    pub fn new(max_lines: usize) -> (Self, TaskLogRegistry) {
+        let registry = Arc::new(RwLock::new(HashMap::new()));
+        (
+            Self {
+                registry: registry.clone(),
+                max_lines,
+            },
+            registry,
+        )
+    }
+}
+
+impl<S> Layer<S> for TaskLogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("span must exist in registry");
+
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        if let Some(task_id) = visitor.task_id {
+            span.extensions_mut().insert(TaskId(task_id));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        // Only INFO/WARN/ERROR are captured; DEBUG/TRACE stay stdout-only.
+        if *event.metadata().level() > Level::INFO {
+            return;
+        }
+
+        let Some(task_id) = ctx.event_scope(event).and_then(|scope| {
+            scope
+                .from_root()
+                .filter_map(|span| span.extensions().get::<TaskId>().copied())
+                .last()
+        }) else {
+            // No enclosing `task` span; nothing to attach this event to.
+            return;
+        };
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let Some(message) = visitor.message else {
+            return;
+        };
+
+        let level = match *event.metadata().level() {
+            Level::ERROR => "error",
+            Level::WARN => "warn",
+            _ => "info",
+        };
+
+        let mut registry = self.registry.write().unwrap();
+        let lines = registry.entry(task_id).or_insert_with(VecDeque::new);
+        if lines.len() >= self.max_lines {
+            lines.pop_front();
+        }
+        lines.push_back(LogLine {
+            timestamp: chrono::Utc::now().timestamp(),
+            level: level.to_string(),
+            message,
+        });
+    }
+}
@@ -2,12 +2,29 @@ use anyhow::Result;
 use crossbeam_channel::{bounded, Receiver, Sender};
 use parking_lot::Mutex;
 use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Arc, RwLock};
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, Notify};
 use tracing::{debug, info, warn};
+use tracing::Instrument;
 
+use super::checkpoint::CheckpointManager;
+use super::disk_layout::DiskLayoutManager;
+use super::manager::Event;
+use super::task_log::{LogLine, TaskLogRegistry};
 use super::task_processor::TaskProcessor;
+use super::task_store::{PersistedTask, TaskSnapshot, TaskStore};
 
+// halcasteel/backup2usb-chromeos#chunk7-wontdo: chunk7-1..8 built a second,
+// parallel worker-pool stack in dynamic_task_manager.rs (work-stealing
+// deques, a MemoryPool, pause/resume/cancel control, wait_drained/
+// shutdown_graceful, a scrub worker, CPU-affinity placement) that nothing
+// outside that file ever constructed - BackupManager::new only ever builds
+// this TaskManager. Wiring it in would mean replacing this already-working
+// loop with untested competing architecture, which is a far larger and
+// riskier change than a follow-up fix warrants. Closed won't-do and the
+// file removed; see 209a79e.
 /// High-performance task manager for coordinating backup operations
 /// Uses lock-free structures and zero-copy message passing
 pub struct TaskManager {
@@ -30,9 +47,71 @@ pub struct TaskManager {
     
     /// Performance metrics
     metrics: Arc<Mutex<Metrics>>,
-    
+
     /// Task processor for handling actual work
     task_processor: Option<Arc<dyn TaskProcessor>>,
+
+    /// Per-worker control channels, separate from `work_sender` so a
+    /// pause/resume/cancel doesn't have to wait behind queued work items.
+    control_senders: Arc<RwLock<Vec<Sender<Control>>>>,
+
+    /// Shared pause flag workers check between rsync output lines, so a
+    /// long transfer yields promptly instead of running to completion.
+    paused: Arc<AtomicBool>,
+
+    /// Wakes workers parked on `paused` as soon as `resume()` is called.
+    resume_notify: Arc<Notify>,
+
+    /// Per-worker liveness/activity, updated by `worker_loop` on every
+    /// transition so `list_workers()` can answer "who's busy/idle/dead".
+    worker_states: Arc<RwLock<HashMap<usize, WorkerState>>>,
+
+    /// Completed-task counter per worker, for `WorkerInfo::tasks_processed`.
+    worker_task_counts: Arc<RwLock<HashMap<usize, u64>>>,
+
+    /// Most recent task failure seen by each worker, kept even after the
+    /// worker goes back to `Idle` so it's still visible in `list_workers()`.
+    worker_last_error: Arc<RwLock<HashMap<usize, String>>>,
+
+    /// Live tranquility knob (0 = full speed) each worker's `Tranquilizer`
+    /// consults after finishing a task, so it can be adjusted mid-run.
+    tranquility: Arc<AtomicU32>,
+
+    /// Tasks popped off `task_queue` but not yet terminal, kept around so a
+    /// snapshot can still describe them after they leave the queue.
+    in_flight_tasks: Arc<RwLock<HashMap<TaskId, Task>>>,
+
+    /// Where to persist `TaskSnapshot`s, if crash recovery is enabled for
+    /// this run. `None` means snapshotting is a no-op.
+    task_store: Option<Arc<dyn TaskStore>>,
+
+    /// Shared with every worker so `process_task` can persist a resume
+    /// cursor as it goes. `None` means crash-consistent resume is disabled.
+    checkpoint_manager: Option<Arc<CheckpointManager>>,
+
+    /// Shared with every worker so `process_task` can pick a destination
+    /// disk for each directory. `None` means only a single destination is
+    /// configured, or the processor (e.g. S3) has no local disk to choose.
+    disk_layout: Option<Arc<DiskLayoutManager>>,
+
+    /// Per-task captured log lines, filled by a `TaskLogLayer` installed on
+    /// the global subscriber and keyed on the `task` span `worker_loop`
+    /// opens around `process_task`.
+    task_logs: TaskLogRegistry,
+
+    /// Broadcasts `Event::WorkerStateChanged` alongside the rest of the
+    /// session's events, if `set_event_tx` wired one up. `None` is a no-op,
+    /// not an error - `list_workers()` still works via a direct poll.
+    event_tx: Option<broadcast::Sender<Event>>,
+}
+
+/// Control-plane messages, distinct from `WorkItem` so they can be acted on
+/// without waiting for the (bounded) work queue to drain.
+#[derive(Debug, Clone, Copy)]
+pub enum Control {
+    Pause,
+    Resume,
+    Cancel(TaskId),
 }
 
 #[derive(Debug, Clone)]
@@ -52,8 +131,10 @@ pub enum TaskStatus {
     Queued,
     Assigned { worker_id: usize },
     Running { worker_id: usize, progress: u8 },
+    Paused { worker_id: usize },
     Completed { duration_ms: u64, bytes_processed: u64 },
     Failed { error: String },
+    Cancelled,
 }
 
 #[derive(Debug)]
@@ -91,6 +172,85 @@ pub struct WorkerHandle {
     pub handle: tokio::task::JoinHandle<()>,
 }
 
+/// Liveness/activity of a single worker, as tracked in `TaskManager::worker_states`.
+#[derive(Debug, Clone)]
+pub enum WorkerState {
+    Idle,
+    Busy {
+        task_id: TaskId,
+        since: Instant,
+        /// Refreshed by the processor on each progress tick (see
+        /// `TaskProcessor::process_task`'s `last_activity` parameter), so
+        /// the dead-worker watchdog can tell "still transferring" apart
+        /// from "stuck", instead of just measuring total busy duration.
+        last_activity: Arc<Mutex<Instant>>,
+    },
+    Dead { error: String },
+}
+
+/// Snapshot of a worker returned by `TaskManager::list_workers()`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WorkerInfo {
+    pub id: usize,
+    pub state: &'static str,
+    pub task_id: Option<u64>,
+    pub busy_for_ms: Option<u64>,
+    pub last_error: Option<String>,
+    pub tasks_processed: u64,
+}
+
+/// Builds one worker's `list_workers()` snapshot, shared between that
+/// method and `worker_loop`'s `Event::WorkerStateChanged` emission so both
+/// describe a worker's state the same way.
+fn describe_worker(
+    id: usize,
+    states: &HashMap<usize, WorkerState>,
+    counts: &HashMap<usize, u64>,
+    errors: &HashMap<usize, String>,
+) -> WorkerInfo {
+    let mut last_error = errors.get(&id).cloned();
+
+    let (state, task_id, busy_for_ms) = match states.get(&id) {
+        Some(WorkerState::Busy { task_id, since, .. }) => {
+            ("busy", Some(task_id.0), Some(since.elapsed().as_millis() as u64))
+        }
+        Some(WorkerState::Dead { error }) => {
+            last_error = Some(error.clone());
+            ("dead", None, None)
+        }
+        Some(WorkerState::Idle) | None => ("idle", None, None),
+    };
+
+    WorkerInfo {
+        id,
+        state,
+        task_id,
+        busy_for_ms,
+        last_error,
+        tasks_processed: counts.get(&id).copied().unwrap_or(0),
+    }
+}
+
+/// Broadcasts a worker's just-updated state, built fresh from the same maps
+/// `list_workers()` reads - a no-op if no `event_tx` was wired up.
+fn emit_worker_state_changed(
+    event_tx: &Option<broadcast::Sender<Event>>,
+    id: usize,
+    worker_states: &Arc<RwLock<HashMap<usize, WorkerState>>>,
+    worker_task_counts: &Arc<RwLock<HashMap<usize, u64>>>,
+    worker_last_error: &Arc<RwLock<HashMap<usize, String>>>,
+) {
+    let Some(event_tx) = event_tx else { return };
+
+    let info = describe_worker(
+        id,
+        &worker_states.read().unwrap(),
+        &worker_task_counts.read().unwrap(),
+        &worker_last_error.read().unwrap(),
+    );
+    let _ = event_tx.send(Event::WorkerStateChanged { id, info });
+}
+
 impl TaskManager {
     pub fn new(num_workers: usize) -> Self {
         // Use bounded channels for backpressure control
@@ -107,14 +267,125 @@ impl TaskManager {
             result_receiver,
             metrics: Arc::new(Mutex::new(Metrics::default())),
             task_processor: None,
+            control_senders: Arc::new(RwLock::new(Vec::new())),
+            paused: Arc::new(AtomicBool::new(false)),
+            resume_notify: Arc::new(Notify::new()),
+            worker_states: Arc::new(RwLock::new(HashMap::new())),
+            worker_task_counts: Arc::new(RwLock::new(HashMap::new())),
+            worker_last_error: Arc::new(RwLock::new(HashMap::new())),
+            tranquility: Arc::new(AtomicU32::new(0)),
+            in_flight_tasks: Arc::new(RwLock::new(HashMap::new())),
+            task_store: None,
+            checkpoint_manager: None,
+            disk_layout: None,
+            task_logs: Arc::new(RwLock::new(HashMap::new())),
+            event_tx: None,
         }
     }
-    
+
     /// Set the task processor
     pub fn set_task_processor(&mut self, processor: Arc<dyn TaskProcessor>) {
         self.task_processor = Some(processor);
     }
-    
+
+    /// Enable crash recovery: every status transition persists a
+    /// `TaskSnapshot` to `store`.
+    pub fn set_task_store(&mut self, store: Arc<dyn TaskStore>) {
+        self.task_store = Some(store);
+    }
+
+    /// Enable crash-consistent resume: every worker gets a handle to
+    /// `manager` and hands it its in-flight position as it processes a task.
+    pub fn set_checkpoint_manager(&mut self, manager: Arc<CheckpointManager>) {
+        self.checkpoint_manager = Some(manager);
+    }
+
+    /// Enable multi-disk placement: every worker gets a handle to `manager`
+    /// and resolves each directory's destination disk through it.
+    pub fn set_disk_layout(&mut self, manager: Arc<DiskLayoutManager>) {
+        self.disk_layout = Some(manager);
+    }
+
+    /// Share a `TaskLogRegistry` with an externally-installed `TaskLogLayer`,
+    /// so logs captured by the tracing layer are visible through `task_log`.
+    pub fn set_task_log_registry(&mut self, registry: TaskLogRegistry) {
+        self.task_logs = registry;
+    }
+
+    /// Share the session's event channel so every worker state transition
+    /// also goes out as `Event::WorkerStateChanged`, not just `list_workers()` polls.
+    pub fn set_event_tx(&mut self, event_tx: broadcast::Sender<Event>) {
+        self.event_tx = Some(event_tx);
+    }
+
+    /// Fetch the captured log lines for a single task, newest-last.
+    pub fn task_log(&self, task_id: TaskId) -> Vec<LogLine> {
+        self.task_logs.read().unwrap()
+            .get(&task_id)
+            .map(|lines| lines.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Re-enqueue whatever a previous run's `TaskStore` snapshot still had
+    /// pending, and seed the rolling metrics so `average_speed_mbps` keeps
+    /// accounting for work done before the crash. No-op if no store is set
+    /// or nothing was persisted.
+    pub async fn restore(&self) -> Result<()> {
+        let Some(store) = self.task_store.clone() else {
+            return Ok(());
+        };
+
+        let Some(snapshot) = store.load().await? else {
+            return Ok(());
+        };
+
+        {
+            let mut metrics = self.metrics.lock();
+            metrics.tasks_completed = snapshot.tasks_completed;
+            metrics.tasks_failed = snapshot.tasks_failed;
+            metrics.total_bytes = snapshot.total_bytes;
+            metrics.total_duration_ms = snapshot.total_duration_ms;
+        }
+
+        let restored = snapshot.pending_tasks.len();
+        let mut queue = self.task_queue.write().unwrap();
+        let mut status = self.task_status.write().unwrap();
+        for persisted in snapshot.pending_tasks {
+            let task = Task {
+                id: TaskId(persisted.id),
+                directory_index: persisted.directory_index,
+                priority: persisted.priority,
+                estimated_size: persisted.estimated_size,
+                created_at: Instant::now(),
+            };
+            status.insert(task.id, TaskStatus::Queued);
+            let insert_pos = queue.iter()
+                .position(|t| t.priority < task.priority)
+                .unwrap_or(queue.len());
+            queue.insert(insert_pos, task);
+        }
+
+        if restored > 0 {
+            info!("Restored {} pending task(s) from snapshot", restored);
+        }
+
+        Ok(())
+    }
+
+    /// Fire-and-forget persistence of the current snapshot, called after
+    /// every status transition. Never blocks the caller on disk IO.
+    fn persist_snapshot(&self) {
+        let Some(store) = self.task_store.clone() else {
+            return;
+        };
+        let snapshot = build_snapshot(&self.task_queue, &self.in_flight_tasks, &self.metrics);
+        tokio::spawn(async move {
+            if let Err(e) = store.save(&snapshot).await {
+                warn!("Failed to persist task snapshot: {}", e);
+            }
+        });
+    }
+
     /// Start the task manager with the specified number of workers
     pub async fn start(
         &mut self,
@@ -128,6 +399,18 @@ impl TaskManager {
         let task_processor = self.task_processor.clone()
             .ok_or_else(|| anyhow::anyhow!("No task processor set"))?;
         
+        // Captured here (rather than inside the spawned task) so it reflects
+        // the `backup_session` span the caller is running in, since the span
+        // stack is per-task and doesn't cross a `tokio::spawn` boundary.
+        let session_span = tracing::Span::current();
+
+        // Fresh control channels and state for this run - pause()/resume()/
+        // cancel_task()/list_workers() target the workers spawned below by index.
+        self.control_senders.write().unwrap().clear();
+        self.worker_states.write().unwrap().clear();
+        self.worker_task_counts.write().unwrap().clear();
+        self.worker_last_error.write().unwrap().clear();
+
         // Spawn worker tasks
         for worker_id in 0..num_workers {
             let work_receiver = self.work_receiver.clone();
@@ -135,21 +418,72 @@ impl TaskManager {
             let task_status = self.task_status.clone();
             let session = session.clone();
             let config = config.clone();
-            
+
+            let (control_sender, control_receiver) = bounded(8);
+            self.control_senders.write().unwrap().push(control_sender);
+
+            self.worker_states.write().unwrap().insert(worker_id, WorkerState::Idle);
+            self.worker_task_counts.write().unwrap().insert(worker_id, 0);
+
             let task_processor_clone = task_processor.clone();
-            
-            let handle = tokio::spawn(async move {
+            let worker_span = session_span.clone();
+            let paused = self.paused.clone();
+            let resume_notify = self.resume_notify.clone();
+            let worker_states = self.worker_states.clone();
+            let worker_task_counts = self.worker_task_counts.clone();
+            let worker_last_error = self.worker_last_error.clone();
+            let tranquility = self.tranquility.clone();
+            let checkpoint_manager = self.checkpoint_manager.clone();
+            let disk_layout = self.disk_layout.clone();
+            let event_tx = self.event_tx.clone();
+
+            let worker_task = tokio::spawn(async move {
                 worker_loop(
                     worker_id,
                     work_receiver,
+                    control_receiver,
                     result_sender,
                     task_status,
                     session,
                     config,
                     task_processor_clone,
+                    paused,
+                    resume_notify,
+                    worker_states,
+                    worker_task_counts,
+                    worker_last_error,
+                    tranquility,
+                    checkpoint_manager,
+                    disk_layout,
+                    event_tx,
                 ).await;
+            }.instrument(worker_span));
+
+            // Watch for the worker task panicking (rather than exiting its
+            // loop normally), since a panicked worker can't update its own
+            // state on the way out.
+            let worker_states_watch = self.worker_states.clone();
+            let worker_task_counts_watch = self.worker_task_counts.clone();
+            let worker_last_error_watch = self.worker_last_error.clone();
+            let event_tx_watch = self.event_tx.clone();
+            let handle = tokio::spawn(async move {
+                if let Err(join_err) = worker_task.await {
+                    if join_err.is_panic() {
+                        worker_states_watch.write().unwrap().insert(
+                            worker_id,
+                            WorkerState::Dead { error: format!("worker panicked: {}", join_err) },
+                        );
+                        emit_worker_state_changed(
+                            &event_tx_watch,
+                            worker_id,
+                            &worker_states_watch,
+                            &worker_task_counts_watch,
+                            &worker_last_error_watch,
+                        );
+                    }
+                }
             });
-            
+
             self.workers.push(WorkerHandle {
                 id: worker_id,
                 handle,
@@ -160,11 +494,56 @@ impl TaskManager {
         let result_receiver = self.result_receiver.clone();
         let metrics = self.metrics.clone();
         let task_status = self.task_status.clone();
-        
+        let task_queue = self.task_queue.clone();
+        let in_flight_tasks = self.in_flight_tasks.clone();
+        let task_store = self.task_store.clone();
+
         tokio::spawn(async move {
-            process_results(result_receiver, metrics, task_status).await;
+            process_results(result_receiver, metrics, task_status, task_queue, in_flight_tasks, task_store).await;
         });
-        
+
+        // Backstop for a worker that's wedged somewhere `stall_timeout_secs`
+        // can't see (that one only guards against a stalled rsync child) -
+        // periodically mark any `Busy` worker whose `last_activity` hasn't
+        // been touched for too long as `Dead` so `list_workers()` stops
+        // reporting it alive.
+        let worker_states_watchdog = self.worker_states.clone();
+        let worker_task_counts_watchdog = self.worker_task_counts.clone();
+        let worker_last_error_watchdog = self.worker_last_error.clone();
+        let event_tx_watchdog = self.event_tx.clone();
+        let dead_timeout = Duration::from_secs(config.worker_dead_timeout_secs);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(10));
+            loop {
+                interval.tick().await;
+
+                let stale: Vec<usize> = worker_states_watchdog
+                    .read()
+                    .unwrap()
+                    .iter()
+                    .filter_map(|(id, state)| match state {
+                        WorkerState::Busy { last_activity, .. } if last_activity.lock().elapsed() > dead_timeout => Some(*id),
+                        _ => None,
+                    })
+                    .collect();
+
+                for id in stale {
+                    warn!("Worker {} has been busy for over {:?} with no progress, marking dead", id, dead_timeout);
+                    worker_states_watchdog.write().unwrap().insert(
+                        id,
+                        WorkerState::Dead { error: format!("no progress for over {:?}", dead_timeout) },
+                    );
+                    emit_worker_state_changed(
+                        &event_tx_watchdog,
+                        id,
+                        &worker_states_watchdog,
+                        &worker_task_counts_watchdog,
+                        &worker_last_error_watchdog,
+                    );
+                }
+            }
+        });
+
         Ok(())
     }
     
@@ -197,16 +576,18 @@ impl TaskManager {
         
         // Try to dispatch immediately
         self.dispatch_next_task();
-        
+        self.persist_snapshot();
+
         task_id
     }
-    
+
     /// Dispatch the next task to an available worker
     fn dispatch_next_task(&self) {
         if let Some(task) = self.task_queue.write().unwrap().pop_front() {
             match self.work_sender.try_send(WorkItem::Task(task.clone())) {
                 Ok(_) => {
                     debug!("Dispatched task {:?}", task.id);
+                    self.in_flight_tasks.write().unwrap().insert(task.id, task);
                 }
                 Err(_) => {
                     // Put it back if channel is full
@@ -216,6 +597,116 @@ impl TaskManager {
         }
     }
     
+    /// Pause all workers. Idle workers stop pulling new tasks and a worker
+    /// mid-transfer stalls at its next cooperative checkpoint instead of
+    /// being torn down, so the work it already did isn't lost.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+        for sender in self.control_senders.read().unwrap().iter() {
+            let _ = sender.send(Control::Pause);
+        }
+    }
+
+    /// Resume workers paused via `pause()`.
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+        self.resume_notify.notify_waiters();
+        for sender in self.control_senders.read().unwrap().iter() {
+            let _ = sender.send(Control::Resume);
+        }
+    }
+
+    /// Cancel a single task. Marks it `Cancelled` immediately so a still
+    /// queued task never starts, and nudges the worker running it (if any)
+    /// so an in-flight transfer stops at its next checkpoint.
+    pub fn cancel_task(&self, task_id: TaskId) {
+        let worker_id = match self.task_status.read().unwrap().get(&task_id) {
+            Some(TaskStatus::Assigned { worker_id })
+            | Some(TaskStatus::Running { worker_id, .. })
+            | Some(TaskStatus::Paused { worker_id }) => Some(*worker_id),
+            _ => None,
+        };
+
+        self.task_status.write().unwrap().insert(task_id, TaskStatus::Cancelled);
+
+        if let Some(worker_id) = worker_id {
+            if let Some(sender) = self.control_senders.read().unwrap().get(worker_id) {
+                let _ = sender.send(Control::Cancel(task_id));
+            }
+        }
+    }
+
+    /// Cancel whichever task (queued or running) is backing `directory_index`,
+    /// if any - lets a single stuck or unwanted directory be aborted without
+    /// stopping the rest of the session. Returns whether a matching task was
+    /// found.
+    pub fn cancel_directory(&self, directory_index: usize) -> bool {
+        let task_id = self.in_flight_tasks.read().unwrap()
+            .values()
+            .find(|task| task.directory_index == directory_index)
+            .map(|task| task.id)
+            .or_else(|| {
+                self.task_queue.read().unwrap()
+                    .iter()
+                    .find(|task| task.directory_index == directory_index)
+                    .map(|task| task.id)
+            });
+
+        match task_id {
+            Some(task_id) => {
+                self.cancel_task(task_id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Cancel every task that hasn't already finished - used when stopping a
+    /// run so in-flight and queued work is interrupted rather than left to
+    /// drain to completion on its own.
+    pub fn cancel_all(&self) {
+        let ids: Vec<TaskId> = self.task_status.read().unwrap().iter()
+            .filter(|(_, status)| !matches!(
+                status,
+                TaskStatus::Completed { .. } | TaskStatus::Failed { .. } | TaskStatus::Cancelled
+            ))
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in ids {
+            self.cancel_task(id);
+        }
+    }
+
+    /// Set the tranquility level (0 = full speed) workers consult after
+    /// finishing each task, so throttling can be tuned live mid-run.
+    pub fn set_tranquility(&self, tranquility: u32) {
+        info!("Setting tranquility to {}", tranquility);
+        self.tranquility.store(tranquility, Ordering::SeqCst);
+    }
+
+    /// Shares the live tranquility knob with another subsystem (e.g.
+    /// `VerifyWorker`) so it paces itself by the same value backup workers do.
+    pub fn tranquility_handle(&self) -> Arc<AtomicU32> {
+        self.tranquility.clone()
+    }
+
+    /// List every worker with its current activity, last error, and how many
+    /// tasks it has completed - Garage's "list running workers and whether
+    /// they are active, idle, or dead" capability.
+    pub fn list_workers(&self) -> Vec<WorkerInfo> {
+        let states = self.worker_states.read().unwrap();
+        let counts = self.worker_task_counts.read().unwrap();
+        let errors = self.worker_last_error.read().unwrap();
+
+        let mut ids: Vec<usize> = states.keys().copied().collect();
+        ids.sort_unstable();
+
+        ids.into_iter()
+            .map(|id| describe_worker(id, &states, &counts, &errors))
+            .collect()
+    }
+
     /// Get current status of all tasks
     pub fn get_status(&self) -> TaskManagerStatus {
         let task_status = self.task_status.read().unwrap();
@@ -261,7 +752,10 @@ impl TaskManager {
         for worker in self.workers.drain(..) {
             let _ = worker.handle.await;
         }
-        
+
+        self.control_senders.write().unwrap().clear();
+        self.paused.store(false, Ordering::SeqCst);
+
         Ok(())
     }
 }
@@ -278,43 +772,128 @@ impl Clone for TaskManager {
             result_receiver: self.result_receiver.clone(),
             metrics: self.metrics.clone(),
             task_processor: self.task_processor.clone(),
+            control_senders: self.control_senders.clone(),
+            paused: self.paused.clone(),
+            resume_notify: self.resume_notify.clone(),
+            worker_states: self.worker_states.clone(),
+            worker_task_counts: self.worker_task_counts.clone(),
+            worker_last_error: self.worker_last_error.clone(),
+            tranquility: self.tranquility.clone(),
+            in_flight_tasks: self.in_flight_tasks.clone(),
+            task_store: self.task_store.clone(),
+            checkpoint_manager: self.checkpoint_manager.clone(),
+            disk_layout: self.disk_layout.clone(),
+            task_logs: self.task_logs.clone(),
+            event_tx: self.event_tx.clone(),
         }
     }
 }
 
 /// Worker loop - runs in separate tokio task
+#[allow(clippy::too_many_arguments)]
 pub async fn worker_loop(
     worker_id: usize,
     work_receiver: Receiver<WorkItem>,
+    control_receiver: Receiver<Control>,
     result_sender: Sender<TaskResult>,
     task_status: Arc<RwLock<HashMap<TaskId, TaskStatus>>>,
     session: super::SharedSession,
     config: Arc<crate::utils::config::Config>,
     task_processor: Arc<dyn TaskProcessor>,
+    paused: Arc<AtomicBool>,
+    resume_notify: Arc<Notify>,
+    worker_states: Arc<RwLock<HashMap<usize, WorkerState>>>,
+    worker_task_counts: Arc<RwLock<HashMap<usize, u64>>>,
+    worker_last_error: Arc<RwLock<HashMap<usize, String>>>,
+    tranquility: Arc<AtomicU32>,
+    checkpoint_manager: Option<Arc<CheckpointManager>>,
+    disk_layout: Option<Arc<DiskLayoutManager>>,
+    event_tx: Option<broadcast::Sender<Event>>,
 ) {
     info!("Worker {} started", worker_id);
-    
+    let mut tranquilizer = crate::utils::Tranquilizer::new();
+
     loop {
-        match work_receiver.recv() {
+        // Don't pull new work while paused; park until resumed or cancelled.
+        while paused.load(Ordering::SeqCst) {
+            resume_notify.notified().await;
+        }
+
+        let picked = crossbeam_channel::select! {
+            recv(control_receiver) -> ctrl => {
+                match ctrl {
+                    Ok(Control::Pause) => paused.store(true, Ordering::SeqCst),
+                    Ok(Control::Resume) => {
+                        paused.store(false, Ordering::SeqCst);
+                        resume_notify.notify_waiters();
+                    }
+                    // Nothing of this worker's is running yet - cancellation
+                    // of a still-queued task is handled by marking its
+                    // status directly in `TaskManager::cancel_task`.
+                    Ok(Control::Cancel(_)) => {}
+                    Err(_) => {}
+                }
+                None
+            }
+            recv(work_receiver) -> work => Some(work),
+        };
+
+        let work = match picked {
+            None => continue,
+            Some(work) => work,
+        };
+
+        match work {
             Ok(WorkItem::Task(task)) => {
-                // Update status to assigned
-                task_status.write().unwrap().insert(
-                    task.id,
-                    TaskStatus::Assigned { worker_id }
+                // A task cancelled while still queued should never start.
+                {
+                    let mut status = task_status.write().unwrap();
+                    if matches!(status.get(&task.id), Some(TaskStatus::Cancelled)) {
+                        debug!("Worker {} skipping cancelled task {:?}", worker_id, task.id);
+                        continue;
+                    }
+                    status.insert(task.id, TaskStatus::Assigned { worker_id });
+                }
+
+                let last_activity = Arc::new(Mutex::new(Instant::now()));
+                worker_states.write().unwrap().insert(
+                    worker_id,
+                    WorkerState::Busy { task_id: task.id, since: Instant::now(), last_activity: last_activity.clone() },
                 );
-                
-                // Process the task
+                emit_worker_state_changed(&event_tx, worker_id, &worker_states, &worker_task_counts, &worker_last_error);
+
+                // Process the task. The `task` span carries `task_id` as a
+                // field so `TaskLogLayer` can attribute every event emitted
+                // underneath it (by `process_task` and anything it calls)
+                // back to this specific task.
+                let task_span = tracing::info_span!("task", task_id = task.id.0);
                 let start = Instant::now();
+                tranquilizer.begin();
                 let result = task_processor.process_task(
                     worker_id,
                     &task,
                     &session,
                     &config,
-                ).await;
-                
+                    paused.clone(),
+                    resume_notify.clone(),
+                    task_status.clone(),
+                    checkpoint_manager.clone(),
+                    disk_layout.clone(),
+                    last_activity,
+                ).instrument(task_span).await;
+
                 let duration_ms = start.elapsed().as_millis() as u64;
-                
-                // Send result
+
+                // Pace the worker's duty cycle before it looks for more work.
+                tranquilizer.tranquilize(tranquility.load(Ordering::SeqCst)).await;
+
+                // Send result, unless the task was cancelled mid-flight, in
+                // which case its status already reflects that.
+                let already_cancelled = matches!(
+                    task_status.read().unwrap().get(&task.id),
+                    Some(TaskStatus::Cancelled)
+                );
+
                 let (status, metrics) = match result {
                     Ok(metrics) => (
                         TaskStatus::Completed {
@@ -323,14 +902,22 @@ pub async fn worker_loop(
                         },
                         metrics
                     ),
-                    Err(e) => (
-                        TaskStatus::Failed {
-                            error: e.to_string(),
-                        },
-                        TaskMetrics::default()
-                    ),
+                    Err(_) if already_cancelled => (TaskStatus::Cancelled, TaskMetrics::default()),
+                    Err(e) => {
+                        worker_last_error.write().unwrap().insert(worker_id, e.to_string());
+                        (
+                            TaskStatus::Failed {
+                                error: e.to_string(),
+                            },
+                            TaskMetrics::default()
+                        )
+                    }
                 };
-                
+
+                *worker_task_counts.write().unwrap().entry(worker_id).or_insert(0) += 1;
+                worker_states.write().unwrap().insert(worker_id, WorkerState::Idle);
+                emit_worker_state_changed(&event_tx, worker_id, &worker_states, &worker_task_counts, &worker_last_error);
+
                 let _ = result_sender.send(TaskResult {
                     task_id: task.id,
                     worker_id,
@@ -344,6 +931,11 @@ pub async fn worker_loop(
             }
             Err(_) => {
                 warn!("Worker {} channel closed", worker_id);
+                worker_states.write().unwrap().insert(
+                    worker_id,
+                    WorkerState::Dead { error: "work channel closed".to_string() },
+                );
+                emit_worker_state_changed(&event_tx, worker_id, &worker_states, &worker_task_counts, &worker_last_error);
                 break;
             }
         }
@@ -356,29 +948,81 @@ async fn process_results(
     result_receiver: Receiver<TaskResult>,
     metrics: Arc<Mutex<Metrics>>,
     task_status: Arc<RwLock<HashMap<TaskId, TaskStatus>>>,
+    task_queue: Arc<RwLock<VecDeque<Task>>>,
+    in_flight_tasks: Arc<RwLock<HashMap<TaskId, Task>>>,
+    task_store: Option<Arc<dyn TaskStore>>,
 ) {
     while let Ok(result) = result_receiver.recv() {
         // Update task status
         task_status.write().unwrap().insert(result.task_id, result.status.clone());
-        
+
         // Update metrics
-        let mut m = metrics.lock();
-        match &result.status {
-            TaskStatus::Completed { duration_ms, bytes_processed } => {
-                m.tasks_completed += 1;
-                m.total_bytes += bytes_processed;
-                m.total_duration_ms += duration_ms;
-            }
-            TaskStatus::Failed { .. } => {
-                m.tasks_failed += 1;
+        {
+            let mut m = metrics.lock();
+            match &result.status {
+                TaskStatus::Completed { duration_ms, bytes_processed } => {
+                    m.tasks_completed += 1;
+                    m.total_bytes += bytes_processed;
+                    m.total_duration_ms += duration_ms;
+                }
+                TaskStatus::Failed { .. } => {
+                    m.tasks_failed += 1;
+                }
+                _ => {}
             }
-            _ => {}
         }
-        
+
+        // A terminal status means the task is done with for good - it no
+        // longer needs to be carried in a snapshot.
+        if matches!(result.status, TaskStatus::Completed { .. } | TaskStatus::Failed { .. } | TaskStatus::Cancelled) {
+            in_flight_tasks.write().unwrap().remove(&result.task_id);
+        }
+
+        if let Some(store) = task_store.clone() {
+            let snapshot = build_snapshot(&task_queue, &in_flight_tasks, &metrics);
+            tokio::spawn(async move {
+                if let Err(e) = store.save(&snapshot).await {
+                    warn!("Failed to persist task snapshot: {}", e);
+                }
+            });
+        }
+
         debug!("Task {:?} completed by worker {}", result.task_id, result.worker_id);
     }
 }
 
+/// Shared by `TaskManager::persist_snapshot` and `process_results` so both build the
+/// same `TaskSnapshot` shape from the queue, in-flight map, and metrics.
+fn build_snapshot(
+    task_queue: &Arc<RwLock<VecDeque<Task>>>,
+    in_flight_tasks: &Arc<RwLock<HashMap<TaskId, Task>>>,
+    metrics: &Arc<Mutex<Metrics>>,
+) -> TaskSnapshot {
+    let metrics = metrics.lock();
+    let mut pending_tasks: Vec<PersistedTask> = task_queue.read().unwrap().iter()
+        .map(|t| PersistedTask {
+            id: t.id.0,
+            directory_index: t.directory_index,
+            priority: t.priority,
+            estimated_size: t.estimated_size,
+        })
+        .collect();
+    pending_tasks.extend(in_flight_tasks.read().unwrap().values().map(|t| PersistedTask {
+        id: t.id.0,
+        directory_index: t.directory_index,
+        priority: t.priority,
+        estimated_size: t.estimated_size,
+    }));
+
+    TaskSnapshot {
+        pending_tasks,
+        tasks_completed: metrics.tasks_completed,
+        tasks_failed: metrics.tasks_failed,
+        total_bytes: metrics.total_bytes,
+        total_duration_ms: metrics.total_duration_ms,
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TaskManagerStatus {
     pub queued_tasks: usize,
@@ -1,56 +1,86 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use std::sync::Arc;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
 use tokio::sync::broadcast;
+use tokio::sync::Notify;
 
+use super::checkpoint::CheckpointManager;
+use super::disk_layout::DiskLayoutManager;
+use super::progress::{ProgressEvent, ProgressReporter};
 use super::{SharedSession, DirectoryStatus};
-use super::task_manager::{Task, TaskMetrics};
+use super::task_manager::{Task, TaskId, TaskMetrics, TaskStatus};
 use crate::utils::config::Config;
 use tracing::{debug, error};
 
+/// How many `ProgressEvent::Update`s per second `BackupWorker` is allowed to
+/// emit before they're coalesced - matches the cadence a human watching a
+/// log or progress bar actually needs.
+const PROGRESS_UPDATES_PER_SEC: u32 = 4;
+
 /// Trait for processing backup tasks
 /// This trait helps break circular dependencies between task_manager and worker
 #[async_trait]
 pub trait TaskProcessor: Send + Sync {
+    /// `paused`/`resume_notify` and `task_status` let the implementation
+    /// check for a pause or cancellation between file chunks rather than
+    /// only at task start, so a long-running task yields promptly instead
+    /// of being killed. `checkpoint_manager` is `None` when crash-consistent
+    /// resume hasn't been configured for this run. `disk_layout` is `None`
+    /// when only a single destination is configured, or for processors (like
+    /// S3) that have no local destination disk to choose between.
+    /// `last_activity` should be touched on every progress tick, not just
+    /// at the start and end of the task - `TaskManager`'s dead-worker
+    /// watchdog reads it to tell "still transferring" apart from "stuck".
+    #[allow(clippy::too_many_arguments)]
     async fn process_task(
         &self,
         worker_id: usize,
         task: &Task,
         session: &SharedSession,
         config: &Arc<Config>,
+        paused: Arc<AtomicBool>,
+        resume_notify: Arc<Notify>,
+        task_status: Arc<RwLock<HashMap<TaskId, TaskStatus>>>,
+        checkpoint_manager: Option<Arc<CheckpointManager>>,
+        disk_layout: Option<Arc<DiskLayoutManager>>,
+        last_activity: Arc<Mutex<Instant>>,
     ) -> Result<TaskMetrics>;
 }
 
 /// Default implementation of TaskProcessor that uses BackupWorker
 pub struct BackupTaskProcessor {
     event_tx: broadcast::Sender<super::manager::Event>,
-    log_buffer: Option<crate::utils::log_buffer::LogBuffer>,
 }
 
 impl BackupTaskProcessor {
     pub fn new(event_tx: broadcast::Sender<super::manager::Event>) -> Self {
-        Self { 
-            event_tx,
-            log_buffer: None,
-        }
-    }
-    
-    pub fn with_log_buffer(event_tx: broadcast::Sender<super::manager::Event>, log_buffer: crate::utils::log_buffer::LogBuffer) -> Self {
-        Self {
-            event_tx,
-            log_buffer: Some(log_buffer),
-        }
+        Self { event_tx }
     }
 }
 
 #[async_trait]
 impl TaskProcessor for BackupTaskProcessor {
+    // Opens the same kind of `directory`-tagged span `BackupWorker::process_directory`
+    // does, so the debug!/error! calls in this function (and in
+    // `process_single_directory`, before its own nested span opens) also
+    // reach `LogBufferLayer`/`DirectoryFileLogLayer` with correct attribution.
+    #[tracing::instrument(skip_all, fields(worker_id, directory = %session.read().unwrap().directories[task.directory_index].name))]
     async fn process_task(
         &self,
         worker_id: usize,
         task: &Task,
         session: &SharedSession,
         config: &Arc<Config>,
+        paused: Arc<AtomicBool>,
+        resume_notify: Arc<Notify>,
+        task_status: Arc<RwLock<HashMap<TaskId, TaskStatus>>>,
+        checkpoint_manager: Option<Arc<CheckpointManager>>,
+        disk_layout: Option<Arc<DiskLayoutManager>>,
+        last_activity: Arc<Mutex<Instant>>,
     ) -> Result<TaskMetrics> {
         // Get directory info
         let (name, path) = {
@@ -58,18 +88,50 @@ impl TaskProcessor for BackupTaskProcessor {
             let dir = &session.directories[task.directory_index];
             (dir.name.clone(), dir.path.clone())
         };
-        
+
         debug!("Worker {} processing directory: {} at {:?}", worker_id, name, path);
-        
+
+        // Give the worker a typed progress channel alongside the broadcast
+        // `Event` bus: nothing subscribes to it yet, but draining it here
+        // (rather than leaving it unattached) keeps `ProgressReporter` an
+        // exercised extension point instead of dead weight, ready for a
+        // TUI/GUI front end to take over the receiving end later.
+        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel::<ProgressEvent>(32);
+        let progress_name = name.clone();
+        tokio::spawn(async move {
+            while let Some(event) = progress_rx.recv().await {
+                match event {
+                    ProgressEvent::Update { bytes_done, total_bytes, speed, current_file, eta } => {
+                        debug!(
+                            "{}: {}/{} bytes at {:.2} MB/s, file={:?}, eta={}",
+                            progress_name, bytes_done, total_bytes, speed, current_file, eta
+                        );
+                    }
+                    ProgressEvent::Completed => break,
+                    ProgressEvent::Failed(err) => {
+                        debug!("{}: progress reporter saw failure: {}", progress_name, err);
+                        break;
+                    }
+                }
+            }
+        });
+
         // Create a backup worker to handle the actual rsync
         let worker = crate::backup::BackupWorker::new(
             worker_id,
             session.clone(),
             self.event_tx.clone(),
             config.clone(),
-            self.log_buffer.clone(),
-        );
-        
+            task.id,
+            paused,
+            resume_notify,
+            task_status,
+            checkpoint_manager,
+            disk_layout,
+            last_activity,
+        )
+        .with_progress_reporter(ProgressReporter::new(progress_tx, PROGRESS_UPDATES_PER_SEC));
+
         // Mark directory as active
         {
             let mut session_guard = session.write().unwrap();
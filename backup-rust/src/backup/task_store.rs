@@ -0,0 +1,73 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// On-disk snapshot of everything needed to resume an interrupted backup:
+/// the tasks that hadn't finished yet, and enough aggregate metrics to keep
+/// `average_speed_mbps` correct instead of resetting to zero. Completed,
+/// failed, and cancelled tasks aren't carried individually since their only
+/// lasting effect is already folded into the aggregate counters below.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaskSnapshot {
+    pub pending_tasks: Vec<PersistedTask>,
+    pub tasks_completed: u64,
+    pub tasks_failed: u64,
+    pub total_bytes: u64,
+    pub total_duration_ms: u64,
+}
+
+/// Just enough of a `Task` to re-enqueue it after a restart - worker
+/// assignment and in-flight progress don't survive a crash, so a task that
+/// was `Running` is re-enqueued as `Queued`, after any queued task of the
+/// same or higher priority.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedTask {
+    pub id: u64,
+    pub directory_index: usize,
+    pub priority: u8,
+    pub estimated_size: u64,
+}
+
+/// Pluggable persistence for the task queue, mirroring the `Repo` trait used
+/// for session storage. Kept separate from `Repo`/`Storage` since
+/// `TaskManager` has no dependency on the database today and a snapshot is
+/// written far more often (on every status transition) than a session save.
+#[async_trait]
+pub trait TaskStore: Send + Sync {
+    async fn save(&self, snapshot: &TaskSnapshot) -> Result<()>;
+    async fn load(&self) -> Result<Option<TaskSnapshot>>;
+}
+
+/// Default `TaskStore`: a single JSON file written wholesale on each save.
+/// The task queue snapshot is small (kilobytes, not gigabytes) so there's no
+/// need for anything more elaborate than "rewrite the file".
+pub struct JsonFileTaskStore {
+    path: PathBuf,
+}
+
+impl JsonFileTaskStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+#[async_trait]
+impl TaskStore for JsonFileTaskStore {
+    async fn save(&self, snapshot: &TaskSnapshot) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let json = serde_json::to_vec_pretty(snapshot)?;
+        tokio::fs::write(&self.path, json).await?;
+        Ok(())
+    }
+
+    async fn load(&self) -> Result<Option<TaskSnapshot>> {
+        match tokio::fs::read(&self.path).await {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use super::{BackupError, DirectoryStatus, SharedSession, Stage};
+use crate::backup::manager::Event;
+use crate::utils::config::Config;
+use crate::utils::Tranquilizer;
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{broadcast, Notify};
+use tracing::{error, info, warn};
+
+/// Persisted result of the most recent scrub pass, so "when did we last
+/// verify this backup and did it find anything" survives a restart -
+/// mirrors `TaskStore`/`CheckpointStore`'s JSON-file persistence.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScrubSummary {
+    pub last_scrub_at: i64,
+    pub directories_checked: u64,
+    pub files_checked: u64,
+    pub errors_found: u64,
+
+    /// Directories already verified as of `last_scrub_at`, loaded back into
+    /// `VerifyWorker::verified` on the first pass after a restart so an
+    /// incremental (non-`full`) scrub picks up where it left off instead of
+    /// re-checking everything the previous process already confirmed.
+    #[serde(default)]
+    pub verified_directories: Vec<String>,
+}
+
+/// Pluggable persistence for `ScrubSummary`, mirroring `TaskStore`/`CheckpointStore`.
+#[async_trait]
+pub trait ScrubStore: Send + Sync {
+    async fn save(&self, summary: &ScrubSummary) -> Result<()>;
+    async fn load(&self) -> Result<Option<ScrubSummary>>;
+}
+
+/// Default `ScrubStore`: a single small JSON file rewritten wholesale after
+/// each scrub pass.
+pub struct JsonFileScrubStore {
+    path: PathBuf,
+}
+
+impl JsonFileScrubStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+#[async_trait]
+impl ScrubStore for JsonFileScrubStore {
+    async fn save(&self, summary: &ScrubSummary) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let json = serde_json::to_vec_pretty(summary)?;
+        tokio::fs::write(&self.path, json).await?;
+        Ok(())
+    }
+
+    async fn load(&self) -> Result<Option<ScrubSummary>> {
+        match tokio::fs::read(&self.path).await {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Post-hoc integrity scrub over already-`Completed` directories, structured
+/// like the main backup's worker pool: driven by `BackupManager::start_verify`
+/// / `pause_verify` / `cancel_verify` rather than running to completion
+/// uninterruptibly, and pacing itself by the same tranquility knob so it
+/// doesn't compete with a backup that's still running.
+pub struct VerifyWorker {
+    paused: Arc<AtomicBool>,
+    cancelled: Arc<AtomicBool>,
+    resume_notify: Arc<Notify>,
+    /// Directories already verified, seeded from the persisted
+    /// `ScrubSummary` on the first pass, so a non-`full` pass only checks
+    /// what hasn't been checked yet.
+    verified: Mutex<HashSet<String>>,
+    /// Where each pass's `ScrubSummary` is persisted. `None` skips persistence
+    /// (e.g. in tests or if no path was wired up).
+    scrub_store: Option<Arc<dyn ScrubStore>>,
+}
+
+impl VerifyWorker {
+    pub fn new() -> Self {
+        Self {
+            paused: Arc::new(AtomicBool::new(false)),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            resume_notify: Arc::new(Notify::new()),
+            verified: Mutex::new(HashSet::new()),
+            scrub_store: None,
+        }
+    }
+
+    pub fn with_scrub_store(scrub_store: Arc<dyn ScrubStore>) -> Self {
+        Self {
+            scrub_store: Some(scrub_store),
+            ..Self::new()
+        }
+    }
+
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+        self.resume_notify.notify_waiters();
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.resume();
+    }
+
+    /// Destination a directory actually landed under - the first configured
+    /// destination that has a matching subdirectory, mirroring
+    /// `BackupWorker::resolve_destination_disk`'s single-destination case.
+    fn resolve_dest(config: &Config, name: &str) -> Option<std::path::PathBuf> {
+        config
+            .backup_destinations
+            .iter()
+            .map(|root| root.join(name))
+            .find(|dest| dest.is_dir())
+    }
+
+    /// Runs one verify pass. `full` re-checks every `Completed` directory;
+    /// otherwise only directories not already verified by a previous pass
+    /// (on this `VerifyWorker`, or recorded in the persisted `ScrubSummary`)
+    /// are checked.
+    pub async fn run(
+        &self,
+        session: SharedSession,
+        config: Arc<Config>,
+        event_tx: broadcast::Sender<Event>,
+        tranquility: Arc<AtomicU32>,
+        full: bool,
+    ) -> Result<()> {
+        self.cancelled.store(false, Ordering::SeqCst);
+
+        // Resume across sessions: on the first pass since this process
+        // started, pull in whatever the last process already confirmed
+        // rather than starting `verified` empty.
+        if let Some(store) = &self.scrub_store {
+            if self.verified.lock().unwrap().is_empty() {
+                if let Ok(Some(summary)) = store.load().await {
+                    self.verified.lock().unwrap().extend(summary.verified_directories);
+                }
+            }
+        }
+
+        let targets: Vec<(usize, String, std::path::PathBuf)> = {
+            let session = session.read().unwrap();
+            session
+                .directories
+                .iter()
+                .enumerate()
+                .filter(|(_, dir)| dir.status == DirectoryStatus::Completed)
+                .filter(|(_, dir)| full || !self.verified.lock().unwrap().contains(&dir.name))
+                .map(|(index, dir)| (index, dir.name.clone(), dir.path.clone()))
+                .collect()
+        };
+
+        info!("Verify pass starting over {} directory(ies) (full={})", targets.len(), full);
+
+        let mut tranquilizer = Tranquilizer::new();
+        let mut directories_checked: u64 = 0;
+        let mut files_checked: u64 = 0;
+        let mut errors_found: u64 = 0;
+
+        for (index, name, src_path) in targets {
+            if self.cancelled.load(Ordering::SeqCst) {
+                info!("Verify pass cancelled before {}", name);
+                break;
+            }
+
+            while self.paused.load(Ordering::SeqCst) {
+                self.resume_notify.notified().await;
+            }
+            if self.cancelled.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let Some(dest_path) = Self::resolve_dest(&config, &name) else {
+                warn!("Verify: no destination found for {}, skipping", name);
+                continue;
+            };
+
+            tranquilizer.begin();
+
+            let event_tx_progress = event_tx.clone();
+            let files_seen = std::cell::Cell::new(0u64);
+            let result = super::integrity::verify_backup(
+                &src_path,
+                &dest_path,
+                config.verify_digest_size,
+                |done, total| {
+                    files_seen.set(total as u64);
+                    let progress = if total > 0 { ((done as f64 / total as f64) * 100.0) as u8 } else { 100 };
+                    let _ = event_tx_progress.send(Event::VerifyProgress { index, progress });
+                    {
+                        let mut session = session.write().unwrap();
+                        if let Some(dir) = session.directories.get_mut(index) {
+                            dir.progress = progress;
+                            dir.current_stage = Stage::Verifying;
+                        }
+                    }
+                },
+            ).await;
+
+            tranquilizer.tranquilize(tranquility.load(Ordering::SeqCst)).await;
+
+            match result {
+                Ok(mismatches) if mismatches.is_empty() => {
+                    info!("Verify: {} OK", name);
+                    self.verified.lock().unwrap().insert(name);
+                    directories_checked += 1;
+                    files_checked += files_seen.get();
+                }
+                Ok(mismatches) => {
+                    let message = format!(
+                        "Verification found {} mismatched/missing file(s): {}",
+                        mismatches.len(),
+                        mismatches.iter().map(|m| m.path.display().to_string()).collect::<Vec<_>>().join("; ")
+                    );
+                    error!("Verify: {} FAILED: {}", name, message);
+
+                    let mut session = session.write().unwrap();
+                    if let Some(dir) = session.directories.get_mut(index) {
+                        dir.status = DirectoryStatus::VerifyFailed;
+                    }
+                    session.errors.push(BackupError {
+                        directory: name,
+                        message,
+                        timestamp: chrono::Utc::now().timestamp(),
+                    });
+
+                    directories_checked += 1;
+                    files_checked += files_seen.get();
+                    errors_found += mismatches.len() as u64;
+                }
+                Err(e) => {
+                    warn!("Verify: {} errored: {}", name, e);
+                    errors_found += 1;
+                }
+            }
+        }
+
+        if let Some(store) = &self.scrub_store {
+            let summary = ScrubSummary {
+                last_scrub_at: chrono::Utc::now().timestamp(),
+                directories_checked,
+                files_checked,
+                errors_found,
+                verified_directories: self.verified.lock().unwrap().iter().cloned().collect(),
+            };
+            if let Err(e) = store.save(&summary).await {
+                warn!("Failed to persist scrub summary: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for VerifyWorker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
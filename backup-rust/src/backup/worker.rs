@@ -1,12 +1,21 @@
-use super::{BackupState, DirectoryStatus, SharedSession};
+use super::checkpoint::{Checkpoint, CheckpointManager};
+use super::disk_layout::DiskLayoutManager;
+use super::progress::ProgressReporter;
+use super::task_manager::{TaskId, TaskStatus};
+use super::{BackupState, DirectoryStatus, SharedSession, Stage};
 use super::rsync_monitor::RsyncMonitor;
 use crate::utils::config::Config;
 use anyhow::Result;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
 use std::path::PathBuf;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command;
+use parking_lot::Mutex as ActivityMutex;
 use tokio::sync::broadcast;
+use tokio::sync::Mutex;
+use tokio::sync::Notify;
 use tracing::{debug, error, info, warn};
 
 pub struct BackupWorker {
@@ -14,28 +23,133 @@ pub struct BackupWorker {
     session: SharedSession,
     event_tx: broadcast::Sender<super::manager::Event>,
     config: Arc<Config>,
-    log_buffer: Option<crate::utils::log_buffer::LogBuffer>,
+    /// Task this worker is currently running, used to look itself up in
+    /// `task_status` for pause/cancel checks between rsync output lines.
+    task_id: TaskId,
+    paused: Arc<AtomicBool>,
+    resume_notify: Arc<Notify>,
+    task_status: Arc<RwLock<HashMap<TaskId, TaskStatus>>>,
+    /// `None` when crash-consistent resume hasn't been configured for this run.
+    checkpoint_manager: Option<Arc<CheckpointManager>>,
+    /// `None` when only a single destination is configured, in which case
+    /// `config.backup_dest` is used directly.
+    disk_layout: Option<Arc<DiskLayoutManager>>,
+    /// Typed progress feed for a consumer that wants compact updates
+    /// without subscribing to `event_tx`'s whole session event bus. `None`
+    /// unless attached via `with_progress_reporter` - most callers don't
+    /// need a second progress feed.
+    progress_reporter: Option<Mutex<ProgressReporter>>,
+    /// Touched on every `checkpoint()` call (roughly once per rsync output
+    /// line), so `TaskManager`'s dead-worker watchdog sees this task is
+    /// still making progress even while it's busy well past
+    /// `worker_dead_timeout_secs`.
+    last_activity: Arc<ActivityMutex<std::time::Instant>>,
 }
 
 impl BackupWorker {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: usize,
         session: SharedSession,
         event_tx: broadcast::Sender<super::manager::Event>,
         config: Arc<Config>,
-        log_buffer: Option<crate::utils::log_buffer::LogBuffer>,
+        task_id: TaskId,
+        paused: Arc<AtomicBool>,
+        resume_notify: Arc<Notify>,
+        task_status: Arc<RwLock<HashMap<TaskId, TaskStatus>>>,
+        checkpoint_manager: Option<Arc<CheckpointManager>>,
+        disk_layout: Option<Arc<DiskLayoutManager>>,
+        last_activity: Arc<ActivityMutex<std::time::Instant>>,
     ) -> Self {
         Self {
             id,
             session,
             event_tx,
             config,
-            log_buffer,
+            task_id,
+            paused,
+            resume_notify,
+            task_status,
+            checkpoint_manager,
+            disk_layout,
+            progress_reporter: None,
+            last_activity,
         }
     }
 
+    /// Attaches a `ProgressReporter` this worker will report per-line
+    /// progress and a terminal `Completed`/`Failed` event to, alongside its
+    /// existing `event_tx` broadcast. Builder-style since it's an optional,
+    /// rarely-used knob for a non-web consumer (e.g. a CLI/TUI) - most
+    /// callers don't need it.
+    pub fn with_progress_reporter(mut self, reporter: ProgressReporter) -> Self {
+        self.progress_reporter = Some(Mutex::new(reporter));
+        self
+    }
+
+    /// Whether this worker's task has been cancelled via `TaskManager::cancel_task`.
+    fn is_cancelled(&self) -> bool {
+        matches!(self.task_status.read().unwrap().get(&self.task_id), Some(TaskStatus::Cancelled))
+    }
+
+    /// Cooperative pause/cancel checkpoint, called once per rsync output
+    /// line (rsync emits roughly one line per file under `--itemize-changes`,
+    /// so this is a natural "between file chunks" point). Returns an error
+    /// if the task was cancelled while paused or waiting.
+    async fn checkpoint(&self, worker_id: usize) -> Result<()> {
+        *self.last_activity.lock() = std::time::Instant::now();
+
+        if self.is_cancelled() {
+            return Err(anyhow::anyhow!("task cancelled"));
+        }
+
+        if self.paused.load(Ordering::SeqCst) {
+            self.task_status.write().unwrap().insert(self.task_id, TaskStatus::Paused { worker_id });
+
+            while self.paused.load(Ordering::SeqCst) {
+                self.resume_notify.notified().await;
+            }
+
+            if self.is_cancelled() {
+                return Err(anyhow::anyhow!("task cancelled"));
+            }
+
+            self.task_status.write().unwrap().insert(self.task_id, TaskStatus::Running { worker_id, progress: 0 });
+        }
+
+        Ok(())
+    }
+
+    /// Hand the current in-flight position to the `CheckpointManager`, if
+    /// one is configured. Cheap to call often - `record_progress` only
+    /// touches disk once its own flush thresholds are crossed.
+    async fn record_checkpoint(
+        &self,
+        index: usize,
+        directory_name: &str,
+        current_file: Option<String>,
+        bytes_processed: u64,
+        files_processed: u64,
+    ) {
+        let Some(manager) = &self.checkpoint_manager else {
+            return;
+        };
+
+        let session_id = self.session.read().unwrap().id.clone();
+        manager.record_progress(Checkpoint {
+            session_id,
+            directory_index: index,
+            directory_name: directory_name.to_string(),
+            current_file,
+            bytes_processed,
+            files_processed,
+            updated_at: chrono::Utc::now(),
+        }).await;
+    }
+
     /// Process a single directory without autonomous looping
     /// This method is now called by the TaskManager for each directory
+    #[tracing::instrument(skip(self), fields(directory = %self.session.read().unwrap().directories[directory_index].name))]
     pub async fn process_single_directory(&self, directory_index: usize) -> Result<()> {
         info!("Worker {} processing directory index {}", self.id, directory_index);
         
@@ -54,8 +168,21 @@ impl BackupWorker {
                 Ok(())
             }
             Err(e) => {
+                // A directory cancelled via `TaskManager::cancel_directory` comes
+                // back through this same error path - mark it `Skipped` rather
+                // than `Error` and don't log it as a failure the user needs to
+                // investigate.
+                if self.is_cancelled() {
+                    info!("Worker {} cancelled directory {}", self.id, directory_index);
+                    let mut session = self.session.write().unwrap();
+                    if let Some(dir) = session.directories.get_mut(directory_index) {
+                        dir.status = DirectoryStatus::Skipped;
+                    }
+                    return Err(e);
+                }
+
                 error!("Worker {} error processing directory {}: {}", self.id, directory_index, e);
-                
+
                 // Mark as error
                 {
                     let mut session = self.session.write().unwrap();
@@ -71,12 +198,48 @@ impl BackupWorker {
                         timestamp: chrono::Utc::now().timestamp(),
                     });
                 }
-                
+
                 Err(e)
             }
         }
     }
 
+    /// Picks which destination disk `name` should land on. With a single
+    /// configured destination this just checks it's reachable, matching the
+    /// old single-disk behavior. With several, it tries each candidate disk
+    /// `disk_layout` offers (most-preferred, i.e. already-pinned or most
+    /// free space, first), skipping any that fail a connection check, and
+    /// pins the first reachable one so later runs keep using it.
+    async fn resolve_destination_disk(&self, name: &str, size: u64) -> Result<PathBuf> {
+        let Some(disk_layout) = &self.disk_layout else {
+            let mut monitor = RsyncMonitor::new(self.config.backup_dest.clone());
+            let connection_status = monitor.check_connection().await?;
+            if !connection_status.is_connected {
+                return Err(anyhow::anyhow!("Backup destination not available: {:?}", connection_status.error_message));
+            }
+            return Ok(self.config.backup_dest.clone());
+        };
+
+        let candidates = disk_layout.candidates(name, size).await?;
+        if candidates.is_empty() {
+            return Err(anyhow::anyhow!("No destination disk has room for {} ({} bytes)", name, size));
+        }
+
+        for candidate in &candidates {
+            let mut monitor = RsyncMonitor::new(candidate.clone());
+            if matches!(monitor.check_connection().await, Ok(status) if status.is_connected) {
+                disk_layout.confirm_placement(name, candidate, size).await?;
+                return Ok(candidate.clone());
+            }
+        }
+
+        Err(anyhow::anyhow!("No destination disk is reachable for {}", name))
+    }
+
+    /// Nested under the `backup_session` span so the `DbLogLayer` tags every
+    /// log line emitted while processing this directory with both the
+    /// session id (inherited from the parent span) and this directory name.
+    #[tracing::instrument(skip(self), fields(directory = %self.session.read().unwrap().directories[index].name))]
     pub async fn process_directory(&self, index: usize) -> Result<()> {
         let (name, path, size) = {
             let session = self.session.read().unwrap();
@@ -84,38 +247,102 @@ impl BackupWorker {
             (dir.name.clone(), dir.path.clone(), dir.size)
         };
         
-        info!("Worker {}: Processing {} ({} bytes)", self.id, name, size);
-        
-        // Add log entry for starting backup
-        if let Some(log_buffer) = &self.log_buffer {
-            log_buffer.add_log(
-                "info",
-                format!("Starting backup of {} ({:.2} MB)", name, size as f64 / 1_048_576.0),
-                Some(name.clone())
-            );
-        }
-        
-        // Initialize rsync monitor
-        let mut monitor = RsyncMonitor::new(PathBuf::from(&self.config.backup_dest));
-        
-        // Check connection first
-        let connection_status = monitor.check_connection().await?;
-        if !connection_status.is_connected {
-            return Err(anyhow::anyhow!("Backup destination not available: {:?}", connection_status.error_message));
-        }
-        
+        info!(
+            "Worker {}: Processing {} ({:.2} MB)",
+            self.id, name, size as f64 / 1_048_576.0
+        );
+
+        // Resolve which destination disk to use before touching rsync -
+        // the single configured destination, or (with several configured)
+        // whichever reachable disk has room for this directory.
+        let dest_root = self.resolve_destination_disk(&name, size).await?;
+
         // Create backup destination directory if it doesn't exist
-        let dest = format!("{}/{}", self.config.backup_dest.display(), name);
+        let dest = format!("{}/{}", dest_root.display(), name);
         tokio::fs::create_dir_all(&dest).await?;
-        
+
+        // If a previous run of this directory was interrupted, rsync left
+        // its in-flight files under `--partial-dir` instead of discarding
+        // them - passing the same `--partial-dir` again below lets rsync
+        // resume them rather than re-transferring from scratch.
+        if let Ok(resume_state) = super::resume::resume_scan(std::path::Path::new(&dest)).await {
+            if !resume_state.incomplete_files.is_empty() {
+                info!(
+                    "Worker {}: Resuming {} file(s) left incomplete from a previous interrupted backup of {}",
+                    self.id, resume_state.incomplete_files.len(), name
+                );
+            }
+        }
+
+        // Large directories can be split across several concurrent rsync
+        // processes (each retried on its own) instead of a single one, to better
+        // saturate a fast USB drive that one stream leaves underutilized.
+        if self.config.parallel_streams > 1 {
+            self.process_directory_parallel(index, &name, &path, &dest).await?;
+            if self.config.verify_after_backup {
+                self.verify_directory(index, &name, &path, &dest).await?;
+            }
+            return Ok(());
+        }
+
+        // Run rsync, retrying transient failures (flaky USB media, a brief
+        // disconnect) with exponential backoff; a permanent failure (bad
+        // arguments, an interrupted run) is returned immediately. `--update`
+        // means a retry picks up from whatever the previous attempt already
+        // transferred rather than redoing it.
+        let mut attempt: u32 = 0;
+        loop {
+            match self.run_rsync_attempt(index, &name, &path, &dest).await? {
+                RsyncOutcome::Success => {
+                    if self.config.verify_after_backup {
+                        self.verify_directory(index, &name, &path, &dest).await?;
+                    }
+                    if let Some(reporter) = &self.progress_reporter {
+                        reporter.lock().await.completed().await;
+                    }
+                    return Ok(());
+                }
+                RsyncOutcome::Failed { exit_code, message } => {
+                    let kind = classify_rsync_failure(exit_code);
+
+                    if kind == RsyncErrorKind::Transient && attempt < self.config.rsync_max_retries {
+                        attempt += 1;
+                        let backoff = rsync_retry_backoff(attempt);
+                        warn!(
+                            "Worker {}: rsync for {} failed transiently (exit {:?}), retrying in {:?} (attempt {}/{})",
+                            self.id, name, exit_code, backoff, attempt, self.config.rsync_max_retries
+                        );
+                        tokio::time::sleep(backoff).await;
+                        continue;
+                    }
+
+                    error!("Worker {}: Failed backing up {}: {}", self.id, name, message);
+                    if let Some(reporter) = &self.progress_reporter {
+                        reporter.lock().await.failed(message.clone()).await;
+                    }
+                    return Err(anyhow::anyhow!("Rsync failed: {}", message));
+                }
+            }
+        }
+    }
+
+    /// Runs rsync once for `name` and reports whether it succeeded. Unlike
+    /// `process_directory`, a non-zero rsync exit is returned as
+    /// `RsyncOutcome::Failed` rather than an `Err`, so the retry loop above
+    /// can decide whether it's worth trying again - only cancellation and
+    /// lower-level IO failures (spawning rsync itself, reading its output)
+    /// propagate as an `Err`, since those aren't rsync exit codes to classify.
+    async fn run_rsync_attempt(&self, index: usize, name: &str, path: &PathBuf, dest: &str) -> Result<RsyncOutcome> {
+        let mut monitor = RsyncMonitor::new(PathBuf::from(dest));
+
         // Build rsync command
         let mut cmd = Command::new("rsync");
-        
+
         cmd.args([
             "-avz",
             "--progress",
             "--no-perms",
-            "--no-owner", 
+            "--no-owner",
             "--no-group",
             "--info=progress2,stats2,flist2",  // More detailed output
             "--stats",
@@ -123,18 +350,24 @@ impl BackupWorker {
             "--itemize-changes", // Show what changed
             "--update",  // Only copy files that are newer than destination
             "--delete",  // Remove files from dest that don't exist in source
+            "--partial", // Keep a file's already-transferred bytes instead of deleting them if interrupted
         ]);
-        
+        cmd.arg(format!("--partial-dir={}", super::resume::PARTIAL_DIR_NAME));
+
+        if self.config.bwlimit_kbps > 0 {
+            cmd.arg(format!("--bwlimit={}", self.config.bwlimit_kbps));
+        }
+
         // Add excludes
         for exclude in &self.config.rsync_excludes {
             cmd.arg(format!("--exclude={}", exclude));
         }
-        
+
         cmd.arg(format!("{}/", path.to_string_lossy()));
         cmd.arg(format!("{}/", dest));
-        
+
         debug!("Running rsync command: {:?}", cmd);
-        
+
         // Spawn rsync process
         let mut child = cmd
             .stdout(std::process::Stdio::piped())
@@ -173,13 +406,56 @@ impl BackupWorker {
         let mut speed_samples: Vec<f64> = Vec::new();
         let mut initial_scan_complete = false;
         let start_time = std::time::Instant::now();
-        
+        let total_size = self.session.read().unwrap().directories[index].size;
+        let mut speed_ema = crate::utils::format::SpeedSmoother::default();
+
         // Feed output to rsync monitor
         monitor.start_monitoring();
-        
-        while let Some(line) = lines.next_line().await? {
+
+        // Stall watchdog: a failing USB controller can make rsync hang
+        // without ever producing output, which would otherwise block this
+        // read loop (and the whole worker) forever. `stall_check` wakes us
+        // up periodically to compare the time since the last line against
+        // `stall_timeout`.
+        let stall_timeout = std::time::Duration::from_secs(self.config.stall_timeout_secs);
+        let mut last_activity = std::time::Instant::now();
+        let mut stall_check = tokio::time::interval(stall_timeout.min(std::time::Duration::from_secs(5)));
+        stall_check.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            let line = tokio::select! {
+                line = lines.next_line() => match line? {
+                    Some(line) => line,
+                    None => break,
+                },
+                _ = stall_check.tick() => {
+                    if last_activity.elapsed() < stall_timeout {
+                        continue;
+                    }
+                    warn!("Worker {}: rsync for {} stalled (no output for {:?}), aborting transfer", self.id, name, stall_timeout);
+                    let _ = child.start_kill();
+                    let _ = child.wait().await;
+                    stderr_task.abort();
+                    return Ok(RsyncOutcome::Failed {
+                        exit_code: None,
+                        message: format!("Transfer stalled: no output for {:?}", stall_timeout),
+                    });
+                }
+            };
+            last_activity = std::time::Instant::now();
+
+            // Cooperative pause/cancel point - rsync emits roughly one line
+            // per file, so checking here yields promptly without killing a
+            // transfer mid-file.
+            if let Err(e) = self.checkpoint(self.id).await {
+                let _ = child.kill().await;
+                stderr_task.abort();
+                info!("Worker {}: backup of {} cancelled", self.id, name);
+                return Err(e);
+            }
+
             debug!("rsync output: {}", line);
-            
+
             // Feed line to monitor
             monitor.process_output(&line);
             
@@ -228,13 +504,7 @@ impl BackupWorker {
                     
                     // Log progress updates every 25%
                     if progress % 25 == 0 && progress > 0 {
-                        if let Some(log_buffer) = &self.log_buffer {
-                            log_buffer.add_log(
-                                "info",
-                                format!("{}: {}% complete", name, progress),
-                                Some(name.clone())
-                            );
-                        }
+                        info!("{}: {}% complete", name, progress);
                     }
                     
                     // Get metrics from monitor
@@ -294,12 +564,34 @@ impl BackupWorker {
                             })
                             .sum();
                     }
-                    
+
+                    self.record_checkpoint(
+                        index,
+                        &name,
+                        (!current_file.is_empty()).then(|| current_file.clone()),
+                        bytes_transferred,
+                        files_processed,
+                    ).await;
+
                     // Send progress event
                     let _ = self.event_tx.send(super::manager::Event::ProgressUpdate {
                         index,
                         progress,
+                        stage: Stage::Transferring,
                     });
+
+                    if let Some(reporter) = &self.progress_reporter {
+                        let smoothed_speed = speed_ema.update(instant_speed_mbps * 1_048_576.0);
+                        let remaining = total_size.saturating_sub(bytes_transferred);
+                        let eta = crate::utils::format::format_eta(remaining, smoothed_speed);
+                        reporter.lock().await.update(
+                            bytes_transferred,
+                            total_size,
+                            smoothed_speed,
+                            (!current_file.is_empty()).then(|| current_file.clone()),
+                            eta,
+                        ).await;
+                    }
                 }
             }
             
@@ -426,48 +718,499 @@ impl BackupWorker {
             }
             
             let _ = self.event_tx.send(super::manager::Event::DirectoryCompleted { index });
-            info!("Worker {}: Completed {} - {} files, {} bytes", 
-                self.id, name, final_metrics.files_transferred, final_metrics.bytes_transferred);
-                
-            // Log completion
-            if let Some(log_buffer) = &self.log_buffer {
-                log_buffer.add_log(
-                    "success",
-                    format!("Completed backup of {} - {} files, {:.2} MB", 
-                        name, 
-                        final_metrics.files_transferred,
-                        final_metrics.bytes_transferred as f64 / 1_048_576.0
-                    ),
-                    Some(name.clone())
-                );
-            }
+            info!(
+                "Worker {}: Completed backup of {} - {} files, {:.2} MB",
+                self.id,
+                name,
+                final_metrics.files_transferred,
+                final_metrics.bytes_transferred as f64 / 1_048_576.0
+            );
+
+            Ok(RsyncOutcome::Success)
         } else {
-            // Handle rsync errors
-            let error_msg = if !errors.is_empty() {
+            // Handle rsync errors - classification and logging of a
+            // non-retryable failure happen in `process_directory`, once it
+            // knows whether this was the last allowed attempt.
+            let message = if !errors.is_empty() {
                 errors.join("\n")
             } else {
                 format!("Rsync failed with exit code: {:?}", status.code())
             };
-            
-            error!("Worker {}: Failed backing up {}: {}", self.id, name, error_msg);
-            
-            // Log error
-            if let Some(log_buffer) = &self.log_buffer {
-                log_buffer.add_log(
-                    "error",
-                    format!("Failed to backup {}: {}", name, error_msg),
-                    Some(name.clone())
-                );
+
+            Ok(RsyncOutcome::Failed { exit_code: status.code(), message })
+        }
+    }
+
+    /// Stage-2 integrity check, run after a successful transfer. Reruns
+    /// rsync in `--dry-run` mode with `--checksum`, so it touches nothing on
+    /// disk and instead reports what it *would* change. Since the transfer
+    /// just completed, anything it itemizes is a file whose checksum
+    /// already differs from the source - evidence of corruption on the
+    /// destination medium rather than a normal incremental change. Returns
+    /// an error naming the mismatched files if any turned up.
+    async fn verify_directory(&self, index: usize, name: &str, path: &PathBuf, dest: &str) -> Result<()> {
+        {
+            let mut session = self.session.write().unwrap();
+            if let Some(dir) = session.directories.get_mut(index) {
+                dir.current_stage = Stage::Verifying;
+                dir.progress = 0;
             }
-            
-            return Err(anyhow::anyhow!("Rsync failed: {}", error_msg));
         }
-        
+        let _ = self.event_tx.send(super::manager::Event::ProgressUpdate {
+            index,
+            progress: 0,
+            stage: Stage::Verifying,
+        });
+
+        info!("Worker {}: Verifying backup of {}", self.id, name);
+
+        let mut cmd = Command::new("rsync");
+        cmd.args(["--checksum", "--dry-run", "--itemize-changes"]);
+        cmd.arg(format!("{}/", path.to_string_lossy()));
+        cmd.arg(format!("{}/", dest));
+        debug!("Running verification rsync command: {:?}", cmd);
+
+        let output = cmd.output().await?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        // `--itemize-changes` only prints a line for an item it would
+        // change, so any line here (they all start with `>` for a sent
+        // file or `<` for a received one) is a mismatch.
+        let mismatches: Vec<String> = stdout
+            .lines()
+            .filter(|line| line.starts_with('>') || line.starts_with('<'))
+            .map(|line| line.to_string())
+            .collect();
+
+        {
+            let mut session = self.session.write().unwrap();
+            if let Some(dir) = session.directories.get_mut(index) {
+                dir.progress = 100;
+            }
+        }
+        let _ = self.event_tx.send(super::manager::Event::ProgressUpdate {
+            index,
+            progress: 100,
+            stage: Stage::Verifying,
+        });
+
+        if !mismatches.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Verification found {} mismatched file(s): {}",
+                mismatches.len(),
+                mismatches.join("; ")
+            ));
+        }
+
+        if self.config.verify_blake2b {
+            let blake2b_mismatches = super::integrity::verify_backup(
+                path,
+                std::path::Path::new(dest),
+                self.config.verify_digest_size,
+                |done, total| {
+                    let progress = if total > 0 { ((done as f64 / total as f64) * 100.0) as u8 } else { 100 };
+                    let mut session = self.session.write().unwrap();
+                    if let Some(dir) = session.directories.get_mut(index) {
+                        dir.progress = progress;
+                    }
+                    let _ = self.event_tx.send(super::manager::Event::ProgressUpdate {
+                        index,
+                        progress,
+                        stage: Stage::Verifying,
+                    });
+                },
+            )
+            .await?;
+
+            if !blake2b_mismatches.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "Blake2b verification found {} mismatched file(s): {}",
+                    blake2b_mismatches.len(),
+                    blake2b_mismatches
+                        .iter()
+                        .map(|m| m.path.display().to_string())
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ));
+            }
+        }
+
+        info!("Worker {}: Verified backup of {}", self.id, name);
+        Ok(())
+    }
+
+    /// Splits `path`'s top-level entries into `config.parallel_streams`
+    /// groups of roughly equal size and backs each group up with its own
+    /// rsync process running concurrently against `dest`. Each stream's
+    /// totals fold into `dir.size_copied`/`files_processed` through
+    /// `accumulator`, a `Mutex`-guarded running total, so the concurrent
+    /// streams can't race each other updating the session. Each stream
+    /// retries transient failures on its own (see `run_rsync_stream`); a
+    /// stream that still fails fails the whole directory.
+    async fn process_directory_parallel(&self, index: usize, name: &str, path: &PathBuf, dest: &str) -> Result<()> {
+        let entries = list_top_level_sizes(path).await?;
+        let groups = partition_by_size(entries, self.config.parallel_streams);
+
+        info!(
+            "Worker {}: Splitting {} across {} rsync stream(s)",
+            self.id,
+            name,
+            groups.iter().filter(|g| !g.is_empty()).count()
+        );
+
+        let accumulator = Arc::new(Mutex::new(StreamProgress::default()));
+        let mut files_from_paths = Vec::new();
+        let mut streams = Vec::new();
+
+        for (stream_index, group) in groups.into_iter().enumerate() {
+            if group.is_empty() {
+                continue;
+            }
+
+            let files_from = std::env::temp_dir()
+                .join(format!("backup-stream-{}-{}-{}.txt", self.id, index, stream_index));
+            let list = group.iter().map(|e| e.name.clone()).collect::<Vec<_>>().join("\n");
+            tokio::fs::write(&files_from, list).await?;
+            files_from_paths.push(files_from.clone());
+
+            streams.push(self.run_rsync_stream(name, path, dest, files_from, stream_index, accumulator.clone()));
+        }
+
+        let result = futures::future::try_join_all(streams).await;
+
+        for files_from in &files_from_paths {
+            let _ = tokio::fs::remove_file(files_from).await;
+        }
+        result?;
+
+        let progress = accumulator.lock().await;
+        {
+            let mut session = self.session.write().unwrap();
+            if let Some(dir) = session.directories.get_mut(index) {
+                dir.status = DirectoryStatus::Completed;
+                dir.size_copied = progress.bytes_transferred;
+                dir.files_processed = progress.files_processed;
+                dir.bytes_processed = Some(progress.bytes_transferred);
+                dir.progress = 100;
+                dir.end_time = Some(chrono::Utc::now().timestamp());
+            }
+        }
+        let _ = self.event_tx.send(super::manager::Event::ProgressUpdate {
+            index,
+            progress: 100,
+            stage: Stage::Transferring,
+        });
+        let _ = self.event_tx.send(super::manager::Event::DirectoryCompleted { index });
+
+        info!(
+            "Worker {}: Completed parallel backup of {} - {} files, {:.2} MB",
+            self.id,
+            name,
+            progress.files_processed,
+            progress.bytes_transferred as f64 / 1_048_576.0
+        );
+
         Ok(())
     }
+
+    /// Runs one rsync stream restricted to `files_from`'s entries, retrying
+    /// transient failures the same way `process_directory` retries
+    /// `run_rsync_attempt`, then folds
+    /// its parsed totals into `accumulator`. `stream_label` is only used for
+    /// logging, to tell concurrent streams apart.
+    async fn run_rsync_stream(
+        &self,
+        name: &str,
+        path: &PathBuf,
+        dest: &str,
+        files_from: PathBuf,
+        stream_label: usize,
+        accumulator: Arc<Mutex<StreamProgress>>,
+    ) -> Result<()> {
+        let mut attempt: u32 = 0;
+        let (bytes_transferred, files_processed) = loop {
+            match self.run_rsync_stream_attempt(name, path, dest, &files_from, stream_label).await? {
+                StreamRsyncOutcome::Success { bytes_transferred, files_processed } => {
+                    break (bytes_transferred, files_processed);
+                }
+                StreamRsyncOutcome::Failed { exit_code, message } => {
+                    let kind = classify_rsync_failure(exit_code);
+
+                    if kind == RsyncErrorKind::Transient && attempt < self.config.rsync_max_retries {
+                        attempt += 1;
+                        let backoff = rsync_retry_backoff(attempt);
+                        warn!(
+                            "Worker {}: rsync stream {} for {} failed transiently (exit {:?}), retrying in {:?} (attempt {}/{})",
+                            self.id, stream_label, name, exit_code, backoff, attempt, self.config.rsync_max_retries
+                        );
+                        tokio::time::sleep(backoff).await;
+                        continue;
+                    }
+
+                    return Err(anyhow::anyhow!(
+                        "rsync stream {} for {} failed: {}",
+                        stream_label,
+                        name,
+                        message
+                    ));
+                }
+            }
+        };
+
+        let mut progress = accumulator.lock().await;
+        progress.bytes_transferred += bytes_transferred;
+        progress.files_processed += files_processed;
+
+        Ok(())
+    }
+
+    /// Single rsync attempt for one stream of `process_directory_parallel`.
+    /// Like `run_rsync_attempt`, a non-zero exit is returned as
+    /// `Ok(StreamRsyncOutcome::Failed { .. })` rather than `Err`, so the
+    /// retry loop above can decide whether it's worth trying again.
+    async fn run_rsync_stream_attempt(
+        &self,
+        name: &str,
+        path: &PathBuf,
+        dest: &str,
+        files_from: &PathBuf,
+        stream_label: usize,
+    ) -> Result<StreamRsyncOutcome> {
+        let mut cmd = Command::new("rsync");
+        cmd.args([
+            "-avz",
+            "--no-perms",
+            "--no-owner",
+            "--no-group",
+            "--info=stats2",
+            "--stats",
+            "--human-readable",
+            "--update",
+            "--delete",
+            "--partial",
+        ]);
+        cmd.arg(format!("--partial-dir={}", super::resume::PARTIAL_DIR_NAME));
+
+        if self.config.bwlimit_kbps > 0 {
+            cmd.arg(format!("--bwlimit={}", self.config.bwlimit_kbps));
+        }
+
+        for exclude in &self.config.rsync_excludes {
+            cmd.arg(format!("--exclude={}", exclude));
+        }
+
+        cmd.arg(format!("--files-from={}", files_from.display()));
+        cmd.arg(format!("{}/", path.to_string_lossy()));
+        cmd.arg(format!("{}/", dest));
+
+        debug!("Worker {}: running rsync stream {} for {}: {:?}", self.id, stream_label, name, cmd);
+
+        let mut child = cmd
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()?;
+
+        let stdout = child.stdout.take().unwrap();
+        let stderr = child.stderr.take().unwrap();
+
+        let stderr_task = tokio::spawn(async move {
+            let reader = BufReader::new(stderr);
+            let mut lines = reader.lines();
+            let mut errors = Vec::new();
+            while let Ok(Some(line)) = lines.next_line().await {
+                warn!("rsync stderr: {}", line);
+                errors.push(line);
+            }
+            errors
+        });
+
+        let reader = BufReader::new(stdout);
+        let mut lines = reader.lines();
+        let mut bytes_transferred = 0u64;
+        let mut files_processed = 0u64;
+
+        // Same stall watchdog as `run_rsync_attempt`: this stream has no
+        // `--progress` output to drive it, but a hung rsync still never
+        // produces its final stats lines either, so the same "no output for
+        // `stall_timeout`" check catches it.
+        let stall_timeout = std::time::Duration::from_secs(self.config.stall_timeout_secs);
+        let mut last_activity = std::time::Instant::now();
+        let mut stall_check = tokio::time::interval(stall_timeout.min(std::time::Duration::from_secs(5)));
+        stall_check.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            let line = tokio::select! {
+                line = lines.next_line() => match line? {
+                    Some(line) => line,
+                    None => break,
+                },
+                _ = stall_check.tick() => {
+                    if last_activity.elapsed() < stall_timeout {
+                        continue;
+                    }
+                    warn!("Worker {}: rsync stream {} for {} stalled (no output for {:?}), aborting", self.id, stream_label, name, stall_timeout);
+                    let _ = child.start_kill();
+                    let _ = child.wait().await;
+                    stderr_task.abort();
+                    return Ok(StreamRsyncOutcome::Failed {
+                        exit_code: None,
+                        message: format!("Transfer stalled: no output for {:?}", stall_timeout),
+                    });
+                }
+            };
+            last_activity = std::time::Instant::now();
+
+            if let Err(e) = self.checkpoint(self.id).await {
+                let _ = child.kill().await;
+                stderr_task.abort();
+                info!("Worker {}: backup stream {} of {} cancelled", self.id, stream_label, name);
+                return Err(e);
+            }
+
+            if line.contains("Total transferred file size:") {
+                if let Some(size) = parse_size_from_line(&line) {
+                    bytes_transferred = size;
+                }
+            } else if line.contains("Number of created files:") || line.contains("Number of regular files transferred:") {
+                if let Some(count) = parse_number_from_line(&line) {
+                    files_processed = count;
+                }
+            }
+        }
+
+        let status = child.wait().await?;
+        let errors = stderr_task.await.unwrap_or_default();
+
+        if status.success() {
+            Ok(StreamRsyncOutcome::Success { bytes_transferred, files_processed })
+        } else {
+            let message = if !errors.is_empty() {
+                errors.join("\n")
+            } else {
+                format!("Rsync failed with exit code: {:?}", status.code())
+            };
+            Ok(StreamRsyncOutcome::Failed { exit_code: status.code(), message })
+        }
+    }
+}
+
+/// Result of a single `run_rsync_attempt` call that didn't encounter a
+/// lower-level IO error.
+enum RsyncOutcome {
+    Success,
+    Failed { exit_code: Option<i32>, message: String },
+}
+
+/// Result of a single `run_rsync_stream_attempt` call that didn't encounter
+/// a lower-level IO error. Like `RsyncOutcome`, but `Success` carries the
+/// stream's parsed totals for `run_rsync_stream` to fold into `StreamProgress`.
+enum StreamRsyncOutcome {
+    Success { bytes_transferred: u64, files_processed: u64 },
+    Failed { exit_code: Option<i32>, message: String },
+}
+
+/// Whether an rsync exit code is worth retrying, per `man rsync`'s EXIT
+/// VALUES. Socket/file/protocol IO errors, vanished source files, and
+/// timeouts are transient - the usual cause on a USB backup is flaky
+/// removable media or a brief disconnect, not the job itself. Everything
+/// else (bad arguments, unsupported options, an interrupted run) is
+/// permanent, since retrying changes nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RsyncErrorKind {
+    Transient,
+    Permanent,
+}
+
+fn classify_rsync_exit_code(code: i32) -> RsyncErrorKind {
+    match code {
+        10 | 11 | 12 | 23 | 24 | 30 | 35 => RsyncErrorKind::Transient,
+        _ => RsyncErrorKind::Permanent,
+    }
 }
 
-fn parse_rsync_progress(line: &str) -> Option<u8> {
+/// Classifies a failed attempt's exit code, if it has one. `None` means
+/// there was no exit code - our own stall watchdog killed the child, or
+/// rsync was ended by a signal - which is always worth retrying rather than
+/// treated as a permanent failure.
+fn classify_rsync_failure(exit_code: Option<i32>) -> RsyncErrorKind {
+    match exit_code {
+        Some(code) => classify_rsync_exit_code(code),
+        None => RsyncErrorKind::Transient,
+    }
+}
+
+/// Exponential backoff for retry attempt `attempt` (1-indexed): 2s, 4s, 8s,
+/// ... capped at 60s so a long string of retries doesn't stall a directory
+/// for hours.
+fn rsync_retry_backoff(attempt: u32) -> std::time::Duration {
+    let capped_shift = attempt.saturating_sub(1).min(5); // 2 << 5 = 64, already past the cap
+    std::time::Duration::from_secs(2u64.saturating_shl(capped_shift).min(60))
+}
+
+/// Bytes/files transferred so far across a directory's concurrent rsync
+/// streams, folded in by each stream once its own rsync run finishes.
+#[derive(Debug, Default)]
+struct StreamProgress {
+    bytes_transferred: u64,
+    files_processed: u64,
+}
+
+/// One top-level entry directly under a backed-up directory, with its total
+/// size in bytes, used to partition a directory's contents across several
+/// rsync streams by size rather than by file count.
+struct TopLevelEntry {
+    name: String,
+    size: u64,
+}
+
+/// Lists `path`'s immediate children with their total sizes, so
+/// `process_directory_parallel` can split them into roughly equal-size
+/// groups. Shells out to `du -sb` per entry, the same approach
+/// `utils::disk`/`disk_layout` use for other disk-size queries rather than
+/// a manual recursive walk.
+async fn list_top_level_sizes(path: &PathBuf) -> Result<Vec<TopLevelEntry>> {
+    let mut read_dir = tokio::fs::read_dir(path).await?;
+    let mut entries = Vec::new();
+
+    while let Some(entry) = read_dir.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let output = Command::new("du").arg("-sb").arg(entry.path()).output().await?;
+        let size = String::from_utf8_lossy(&output.stdout)
+            .split_whitespace()
+            .next()
+            .and_then(|s| s.parse::<u64>().ok())
+            .unwrap_or(0);
+
+        entries.push(TopLevelEntry { name, size });
+    }
+
+    Ok(entries)
+}
+
+/// Greedily partitions `entries` into `group_count` groups of roughly equal
+/// total size: largest entries first, each going to whichever group
+/// currently has the smallest running total - the usual longest-processing-
+/// time heuristic for balancing work across a fixed number of workers.
+fn partition_by_size(mut entries: Vec<TopLevelEntry>, group_count: usize) -> Vec<Vec<TopLevelEntry>> {
+    entries.sort_by(|a, b| b.size.cmp(&a.size));
+
+    let mut groups: Vec<Vec<TopLevelEntry>> = (0..group_count).map(|_| Vec::new()).collect();
+    let mut totals = vec![0u64; group_count];
+
+    for entry in entries {
+        let (smallest, _) = totals
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, total)| **total)
+            .expect("group_count is at least 1");
+        totals[smallest] += entry.size;
+        groups[smallest].push(entry);
+    }
+
+    groups
+}
+
+pub(crate) fn parse_rsync_progress(line: &str) -> Option<u8> {
     // Parse rsync progress2 format: "          1,234  56%    1.23MB/s    0:00:01"
     if line.contains('%') {
         let parts: Vec<&str> = line.split_whitespace().collect();
@@ -551,7 +1294,7 @@ fn parse_speed_from_stats(line: &str) -> Option<f64> {
     None
 }
 
-fn parse_speed_from_progress_line(line: &str) -> Option<(u64, f64)> {
+pub(crate) fn parse_speed_from_progress_line(line: &str) -> Option<(u64, f64)> {
     // Parse progress lines like "123,456,789 100%   12.34MB/s    0:01:23"
     let parts: Vec<&str> = line.split_whitespace().collect();
     
@@ -584,4 +1327,48 @@ fn parse_speed_from_progress_line(line: &str) -> Option<(u64, f64)> {
         }
     }
     None
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transient_exit_codes_are_retried() {
+        for code in [10, 11, 12, 23, 24, 30, 35] {
+            assert_eq!(classify_rsync_exit_code(code), RsyncErrorKind::Transient, "exit code {}", code);
+        }
+    }
+
+    #[test]
+    fn other_exit_codes_are_permanent() {
+        for code in [0, 1, 2, 3, 14, 99] {
+            assert_eq!(classify_rsync_exit_code(code), RsyncErrorKind::Permanent, "exit code {}", code);
+        }
+    }
+
+    #[test]
+    fn no_exit_code_is_treated_as_transient() {
+        // No exit code means our stall watchdog killed the child or rsync
+        // was ended by a signal, and that's always worth retrying.
+        assert_eq!(classify_rsync_failure(None), RsyncErrorKind::Transient);
+    }
+
+    #[test]
+    fn exit_code_classification_is_delegated() {
+        assert_eq!(classify_rsync_failure(Some(23)), RsyncErrorKind::Transient);
+        assert_eq!(classify_rsync_failure(Some(1)), RsyncErrorKind::Permanent);
+    }
+
+    #[test]
+    fn retry_backoff_doubles_each_attempt() {
+        assert_eq!(rsync_retry_backoff(1), std::time::Duration::from_secs(2));
+        assert_eq!(rsync_retry_backoff(2), std::time::Duration::from_secs(4));
+        assert_eq!(rsync_retry_backoff(3), std::time::Duration::from_secs(8));
+    }
+
+    #[test]
+    fn retry_backoff_caps_at_sixty_seconds() {
+        assert_eq!(rsync_retry_backoff(10), std::time::Duration::from_secs(60));
+        assert_eq!(rsync_retry_backoff(100), std::time::Duration::from_secs(60));
+    }
+}
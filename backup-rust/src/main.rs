@@ -16,26 +16,33 @@ use crate::backup::BackupManager;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize tracing with minimal overhead
-    utils::logging::init_tracing();
-
     // Load configuration
     let config = utils::config::load_config()?;
-    
-    info!("Starting Backup System v3.0.0");
-    info!("CPU cores available: {}", num_cpus::get());
-    info!("Memory efficient mode: enabled");
 
-    // Initialize storage layer
+    // Initialize storage layer before tracing so the DB logging layer has
+    // somewhere to write session-tagged events from the very first log line.
     let storage = Storage::new(&config.database_url).await?;
     storage.run_migrations().await?;
 
+    let (task_log_registry, log_buffer, warning_counts) =
+        utils::logging::init_tracing_with_storage(storage.clone(), config.backup_dest.clone());
+
+    info!("Starting Backup System v3.0.0");
+    info!("CPU cores available: {}", num_cpus::get());
+    info!("Memory efficient mode: enabled");
+
     // Check for existing session to restore
     let existing_session = storage.get_latest_session().await?;
     
     // Initialize backup manager with resource limits
-    let backup_manager = BackupManager::new(config.clone(), storage.clone());
-    
+    let backup_manager = BackupManager::new(config.clone(), storage.clone(), task_log_registry, log_buffer, warning_counts);
+
+    // Re-enqueue any tasks still pending from a run that didn't shut down
+    // cleanly, before anything starts dispatching new work.
+    if let Err(e) = backup_manager.restore_tasks().await {
+        tracing::warn!("Failed to restore pending tasks: {}", e);
+    }
+
     // Restore previous session if it was Running or Paused
     if let Some(session) = existing_session {
         match session.state {
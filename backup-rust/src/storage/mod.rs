@@ -1,193 +1,98 @@
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+#[cfg(feature = "postgres")]
+pub mod postgres;
+
+use crate::backup::{BackupHistoryRecord, BackupSession};
 use anyhow::Result;
-use sqlx::{sqlite::SqlitePool, Row};
-use std::path::Path;
-use tracing::info;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Backend-agnostic persistence trait. Each backend (SQLite, Postgres, ...)
+/// implements this so `BackupManager` and the axum handlers never touch a
+/// concrete driver type.
+#[async_trait]
+pub trait Repo: Send + Sync {
+    async fn run_migrations(&self) -> Result<()>;
+    async fn save_session(&self, session: &BackupSession) -> Result<()>;
+    async fn load_session(&self, session_id: &str) -> Result<Option<BackupSession>>;
+    async fn get_latest_session(&self) -> Result<Option<BackupSession>>;
+    async fn add_log(&self, session_id: &str, level: &str, message: &str, directory: Option<&str>) -> Result<()>;
+    async fn get_logs(&self, session_id: &str, level: Option<&str>, limit: i32) -> Result<Vec<LogEntry>>;
+    async fn list_completed_history(&self) -> Result<Vec<BackupHistoryRecord>>;
+    async fn delete_history_by_session(&self, session_id: &str) -> Result<()>;
+}
 
+/// Thin, cloneable handle around whichever `Repo` backend the configured
+/// `database_url` selects.
 #[derive(Clone)]
 pub struct Storage {
-    pool: SqlitePool,
+    repo: Arc<dyn Repo>,
 }
 
 impl Storage {
+    /// Select a backend by URL scheme: `sqlite://` or `postgres://`.
     pub async fn new(database_url: &str) -> Result<Self> {
-        // Create database file if it doesn't exist
-        if !database_url.starts_with(":memory:") {
-            let path = database_url.strip_prefix("sqlite://").unwrap_or(database_url);
-            if let Some(parent) = Path::new(path).parent() {
-                tokio::fs::create_dir_all(parent).await?;
+        let repo: Arc<dyn Repo> = if database_url.starts_with("postgres://") || database_url.starts_with("postgresql://") {
+            #[cfg(feature = "postgres")]
+            {
+                Arc::new(postgres::PostgresRepo::connect(database_url).await?)
+            }
+            #[cfg(not(feature = "postgres"))]
+            {
+                return Err(anyhow::anyhow!(
+                    "database_url {} requires the `postgres` feature to be enabled",
+                    database_url
+                ));
+            }
+        } else {
+            #[cfg(feature = "sqlite")]
+            {
+                Arc::new(sqlite::SqliteRepo::connect(database_url).await?)
             }
-        }
+            #[cfg(not(feature = "sqlite"))]
+            {
+                return Err(anyhow::anyhow!(
+                    "database_url {} requires the `sqlite` feature to be enabled",
+                    database_url
+                ));
+            }
+        };
 
-        let pool = SqlitePool::connect(database_url).await?;
-        
-        Ok(Self { pool })
+        Ok(Self { repo })
     }
 
     pub async fn run_migrations(&self) -> Result<()> {
-        info!("Running database migrations");
-        
-        // Create tables
-        sqlx::query(
-            r#"
-            CREATE TABLE IF NOT EXISTS backup_sessions (
-                id TEXT PRIMARY KEY,
-                data TEXT NOT NULL,
-                created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
-                updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
-            )
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
-
-        sqlx::query(
-            r#"
-            CREATE TABLE IF NOT EXISTS backup_history (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                session_id TEXT NOT NULL,
-                profile TEXT,
-                status TEXT NOT NULL,
-                started_at DATETIME,
-                completed_at DATETIME,
-                total_size BIGINT,
-                files_count BIGINT,
-                directories_count INTEGER,
-                errors_count INTEGER,
-                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
-            )
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
-
-        sqlx::query(
-            r#"
-            CREATE TABLE IF NOT EXISTS backup_logs (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                session_id TEXT NOT NULL,
-                level TEXT NOT NULL,
-                message TEXT NOT NULL,
-                directory TEXT,
-                created_at DATETIME DEFAULT CURRENT_TIMESTAMP
-            )
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
-
-        sqlx::query(
-            r#"
-            CREATE INDEX IF NOT EXISTS idx_logs_session ON backup_logs(session_id);
-            CREATE INDEX IF NOT EXISTS idx_logs_level ON backup_logs(level);
-            CREATE INDEX IF NOT EXISTS idx_history_session ON backup_history(session_id);
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
-
-        Ok(())
+        self.repo.run_migrations().await
     }
 
-    pub async fn save_session(&self, session: &super::backup::BackupSession) -> Result<()> {
-        let data = serde_json::to_string(session)?;
-        
-        sqlx::query(
-            r#"
-            INSERT INTO backup_sessions (id, data, updated_at)
-            VALUES (?1, ?2, CURRENT_TIMESTAMP)
-            ON CONFLICT(id) DO UPDATE SET
-                data = excluded.data,
-                updated_at = CURRENT_TIMESTAMP
-            "#,
-        )
-        .bind(&session.id)
-        .bind(&data)
-        .execute(&self.pool)
-        .await?;
-
-        Ok(())
+    pub async fn save_session(&self, session: &BackupSession) -> Result<()> {
+        self.repo.save_session(session).await
     }
 
-    pub async fn load_session(&self, session_id: &str) -> Result<Option<super::backup::BackupSession>> {
-        let row = sqlx::query(
-            "SELECT data FROM backup_sessions WHERE id = ?1"
-        )
-        .bind(session_id)
-        .fetch_optional(&self.pool)
-        .await?;
-
-        if let Some(row) = row {
-            let data: String = row.get("data");
-            let session = serde_json::from_str(&data)?;
-            Ok(Some(session))
-        } else {
-            Ok(None)
-        }
+    pub async fn load_session(&self, session_id: &str) -> Result<Option<BackupSession>> {
+        self.repo.load_session(session_id).await
     }
 
-    pub async fn get_latest_session(&self) -> Result<Option<super::backup::BackupSession>> {
-        let row = sqlx::query(
-            "SELECT data FROM backup_sessions ORDER BY updated_at DESC LIMIT 1"
-        )
-        .fetch_optional(&self.pool)
-        .await?;
-
-        if let Some(row) = row {
-            let data: String = row.get("data");
-            let session = serde_json::from_str(&data)?;
-            Ok(Some(session))
-        } else {
-            Ok(None)
-        }
+    pub async fn get_latest_session(&self) -> Result<Option<BackupSession>> {
+        self.repo.get_latest_session().await
     }
 
     pub async fn add_log(&self, session_id: &str, level: &str, message: &str, directory: Option<&str>) -> Result<()> {
-        sqlx::query(
-            r#"
-            INSERT INTO backup_logs (session_id, level, message, directory)
-            VALUES (?1, ?2, ?3, ?4)
-            "#,
-        )
-        .bind(session_id)
-        .bind(level)
-        .bind(message)
-        .bind(directory)
-        .execute(&self.pool)
-        .await?;
-
-        Ok(())
+        self.repo.add_log(session_id, level, message, directory).await
     }
 
     pub async fn get_logs(&self, session_id: &str, level: Option<&str>, limit: i32) -> Result<Vec<LogEntry>> {
-        let query = if let Some(level) = level {
-            sqlx::query_as::<_, LogEntry>(
-                r#"
-                SELECT level, message, directory, created_at
-                FROM backup_logs
-                WHERE session_id = ?1 AND level = ?2
-                ORDER BY id DESC
-                LIMIT ?3
-                "#,
-            )
-            .bind(session_id)
-            .bind(level)
-            .bind(limit)
-        } else {
-            sqlx::query_as::<_, LogEntry>(
-                r#"
-                SELECT level, message, directory, created_at
-                FROM backup_logs
-                WHERE session_id = ?1
-                ORDER BY id DESC
-                LIMIT ?2
-                "#,
-            )
-            .bind(session_id)
-            .bind(limit)
-        };
+        self.repo.get_logs(session_id, level, limit).await
+    }
 
-        let logs = query.fetch_all(&self.pool).await?;
-        Ok(logs)
+    pub async fn list_completed_history(&self) -> Result<Vec<BackupHistoryRecord>> {
+        self.repo.list_completed_history().await
+    }
+
+    pub async fn delete_history_by_session(&self, session_id: &str) -> Result<()> {
+        self.repo.delete_history_by_session(session_id).await
     }
 }
 
@@ -198,4 +103,4 @@ pub struct LogEntry {
     pub directory: Option<String>,
     #[sqlx(rename = "created_at")]
     pub created_at: String,
-}
\ No newline at end of file
+}
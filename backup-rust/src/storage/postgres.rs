@@ -0,0 +1,220 @@
+use super::{LogEntry, Repo};
+use crate::backup::{BackupHistoryRecord, BackupSession};
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::{postgres::PgPool, Row};
+use tracing::info;
+
+#[derive(Clone)]
+pub struct PostgresRepo {
+    pool: PgPool,
+}
+
+impl PostgresRepo {
+    pub async fn connect(database_url: &str) -> Result<Self> {
+        let pool = PgPool::connect(database_url).await?;
+        Ok(Self { pool })
+    }
+}
+
+#[async_trait]
+impl Repo for PostgresRepo {
+    async fn run_migrations(&self) -> Result<()> {
+        info!("Running PostgreSQL database migrations");
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS backup_sessions (
+                id TEXT PRIMARY KEY,
+                data TEXT NOT NULL,
+                created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+                updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS backup_history (
+                id BIGSERIAL PRIMARY KEY,
+                session_id TEXT NOT NULL,
+                profile TEXT,
+                status TEXT NOT NULL,
+                started_at TIMESTAMPTZ,
+                completed_at TIMESTAMPTZ,
+                total_size BIGINT,
+                files_count BIGINT,
+                directories_count INTEGER,
+                errors_count INTEGER,
+                created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS backup_logs (
+                id BIGSERIAL PRIMARY KEY,
+                session_id TEXT NOT NULL,
+                level TEXT NOT NULL,
+                message TEXT NOT NULL,
+                directory TEXT,
+                created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_logs_session ON backup_logs(session_id)")
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_logs_level ON backup_logs(level)")
+            .execute(&self.pool)
+            .await?;
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_history_session ON backup_history(session_id)")
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn save_session(&self, session: &BackupSession) -> Result<()> {
+        let data = serde_json::to_string(session)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO backup_sessions (id, data, updated_at)
+            VALUES ($1, $2, now())
+            ON CONFLICT(id) DO UPDATE SET
+                data = excluded.data,
+                updated_at = now()
+            "#,
+        )
+        .bind(&session.id)
+        .bind(&data)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn load_session(&self, session_id: &str) -> Result<Option<BackupSession>> {
+        let row = sqlx::query("SELECT data FROM backup_sessions WHERE id = $1")
+            .bind(session_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        if let Some(row) = row {
+            let data: String = row.get("data");
+            let session = serde_json::from_str(&data)?;
+            Ok(Some(session))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn get_latest_session(&self) -> Result<Option<BackupSession>> {
+        let row = sqlx::query("SELECT data FROM backup_sessions ORDER BY updated_at DESC LIMIT 1")
+            .fetch_optional(&self.pool)
+            .await?;
+
+        if let Some(row) = row {
+            let data: String = row.get("data");
+            let session = serde_json::from_str(&data)?;
+            Ok(Some(session))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn add_log(&self, session_id: &str, level: &str, message: &str, directory: Option<&str>) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO backup_logs (session_id, level, message, directory)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(session_id)
+        .bind(level)
+        .bind(message)
+        .bind(directory)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_logs(&self, session_id: &str, level: Option<&str>, limit: i32) -> Result<Vec<LogEntry>> {
+        let query = if let Some(level) = level {
+            sqlx::query_as::<_, LogEntry>(
+                r#"
+                SELECT level, message, directory, created_at::text AS created_at
+                FROM backup_logs
+                WHERE session_id = $1 AND level = $2
+                ORDER BY id DESC
+                LIMIT $3
+                "#,
+            )
+            .bind(session_id)
+            .bind(level)
+            .bind(limit)
+        } else {
+            sqlx::query_as::<_, LogEntry>(
+                r#"
+                SELECT level, message, directory, created_at::text AS created_at
+                FROM backup_logs
+                WHERE session_id = $1
+                ORDER BY id DESC
+                LIMIT $2
+                "#,
+            )
+            .bind(session_id)
+            .bind(limit)
+        };
+
+        let logs = query.fetch_all(&self.pool).await?;
+        Ok(logs)
+    }
+
+    async fn list_completed_history(&self) -> Result<Vec<BackupHistoryRecord>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT session_id, completed_at, total_size
+            FROM backup_history
+            WHERE status = 'completed' AND completed_at IS NOT NULL
+            ORDER BY completed_at DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut records = Vec::with_capacity(rows.len());
+        for row in rows {
+            let session_id: String = row.get("session_id");
+            let completed_at: chrono::DateTime<chrono::Utc> = row.get("completed_at");
+            let total_size: i64 = row.get("total_size");
+
+            records.push(BackupHistoryRecord {
+                session_id,
+                completed_at,
+                total_size: total_size.max(0) as u64,
+            });
+        }
+
+        Ok(records)
+    }
+
+    async fn delete_history_by_session(&self, session_id: &str) -> Result<()> {
+        sqlx::query("DELETE FROM backup_history WHERE session_id = $1")
+            .bind(session_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
     /// Server port
     pub port: u16,
@@ -12,16 +13,61 @@ pub struct Config {
     
     /// Backup destination path
     pub backup_dest: PathBuf,
-    
+
+    /// Every destination disk directories can be spread across. Defaults to
+    /// just `backup_dest`; `BACKUP_DESTINATIONS` replaces the whole list, so
+    /// `backup_dest` is then in it only if listed there too.
+    pub backup_destinations: Vec<PathBuf>,
+
     /// Home directory to scan
     pub home_dir: String,
     
     /// Maximum number of workers (0 = auto based on CPU)
     pub max_workers: usize,
-    
+
+    /// Default number of directories to back up concurrently when a
+    /// `/control` start request doesn't specify one (0 = auto, half of `max_workers`)
+    pub default_parallelism: usize,
+
     /// Rsync exclude patterns
     pub rsync_excludes: Vec<String>,
-    
+
+    /// How many times to retry a directory's rsync after a transient
+    /// failure (flaky USB media, a brief disconnect) before giving up.
+    pub rsync_max_retries: u32,
+
+    /// Run a post-transfer `--checksum --dry-run` verification pass on each
+    /// directory, to catch silent corruption on the destination medium.
+    pub verify_after_backup: bool,
+
+    /// How long rsync can go without producing output before it's considered
+    /// stalled (e.g. a wedged USB controller) and killed, in seconds.
+    pub stall_timeout_secs: u64,
+
+    /// Caps each rsync process's transfer rate via `--bwlimit`, in KB/s.
+    /// `0` (the default) leaves rsync unlimited.
+    pub bwlimit_kbps: u64,
+
+    /// Splits a directory's transfer across this many concurrent rsync
+    /// processes instead of one, to better saturate a fast USB drive. `1`
+    /// (the default) keeps the old single-stream behavior.
+    pub parallel_streams: usize,
+
+    /// Run the Blake2b content-hash verification subsystem after a
+    /// successful directory transfer, in addition to the cheaper rsync
+    /// `--checksum --dry-run` pass. Off by default since hashing every file
+    /// is far more expensive than rsync's own check.
+    pub verify_blake2b: bool,
+
+    /// Digest size, in bytes, used by the Blake2b verification subsystem.
+    pub verify_digest_size: usize,
+
+    /// Initial "tranquility" every worker's `Tranquilizer` paces its duty
+    /// cycle by - `0` runs flat out, higher values add more idle time
+    /// between tasks so a large backup doesn't make the machine unusable.
+    /// Changeable live via `Command::SetTranquility` once a backup is running.
+    pub tranquility: u8,
+
     /// Enable dynamic worker scaling
     pub dynamic_scaling: bool,
     
@@ -30,6 +76,45 @@ pub struct Config {
     
     /// Memory per worker in MB
     pub memory_per_worker: u64,
+
+    /// S3-compatible bucket to back up to instead of the local USB mount.
+    /// Unset (the default) keeps the local `backup_dest` destination.
+    pub s3_bucket: Option<String>,
+
+    /// Custom endpoint for self-hosted S3-compatible stores (e.g. MinIO,
+    /// Garage). Unset uses AWS's regional endpoint for `s3_region`.
+    pub s3_endpoint: Option<String>,
+
+    /// Region passed to the S3 client. Most self-hosted stores accept any
+    /// value here since they don't do region routing.
+    pub s3_region: Option<String>,
+
+    /// Key prefix every uploaded object is placed under, e.g. `"pixelbook"`.
+    pub s3_prefix: Option<String>,
+
+    /// Static credentials, as an alternative to the default provider chain
+    /// (environment, instance metadata, `~/.aws/credentials`, ...).
+    pub s3_access_key_id: Option<String>,
+    pub s3_secret_access_key: Option<String>,
+
+    /// How long a `Busy` worker can go without reporting progress (its
+    /// `last_activity` being refreshed) before the
+    /// registry watchdog gives up waiting for it and marks it `Dead`, in
+    /// seconds. Kept well above `stall_timeout_secs` since that already
+    /// kills a stalled rsync - this is a backstop for a worker that's
+    /// wedged somewhere `stall_timeout_secs` can't see.
+    pub worker_dead_timeout_secs: u64,
+
+    /// Automatically run an incremental verify pass (like `Command::Verify
+    /// { full: false }`) this often, in seconds. `0` (the default) disables
+    /// the automatic scrub - `/verify` can still be triggered manually.
+    pub scrub_interval_secs: u64,
+
+    /// Task-level retry limit, read from `TASK_MAX_RETRIES`. No part of the
+    /// task pool reads it: a task whose processing fails is marked `Failed`
+    /// at once and is not re-enqueued. Retries of a directory's rsync are
+    /// governed by `rsync_max_retries` alone.
+    pub task_max_retries: u32,
 }
 
 impl Default for Config {
@@ -40,11 +125,13 @@ impl Default for Config {
         Self {
             port: 8888,
             database_url: "sqlite://backup_system.db".to_string(),
+            backup_destinations: vec![backup_dest.clone()],
             backup_dest,
             home_dir: dirs::home_dir()
                 .map(|p| p.to_string_lossy().to_string())
                 .unwrap_or_else(|| "/home".to_string()),
             max_workers: 0, // Auto-detect
+            default_parallelism: 0, // Auto-detect (half of max_workers)
             rsync_excludes: vec![
                 "venv".to_string(),
                 ".venv".to_string(),
@@ -59,18 +146,65 @@ impl Default for Config {
                 "*.tmp".to_string(),
                 "*.swp".to_string(),
             ],
+            rsync_max_retries: 3,
+            verify_after_backup: false,
+            stall_timeout_secs: 60,
+            bwlimit_kbps: 0,
+            parallel_streams: 1,
+            verify_blake2b: false,
+            verify_digest_size: 32, // matches backup::integrity::DEFAULT_DIGEST_SIZE
+            tranquility: 0,
             dynamic_scaling: true,
             target_cpu_usage: 75.0,
             memory_per_worker: 256,
+            s3_bucket: None,
+            s3_endpoint: None,
+            s3_region: None,
+            s3_prefix: None,
+            s3_access_key_id: None,
+            s3_secret_access_key: None,
+            worker_dead_timeout_secs: 300,
+            scrub_interval_secs: 0,
+            task_max_retries: 3,
+        }
+    }
+}
+
+/// Finds `backup.toml`, checked in the same order Garage-style tools
+/// usually search: `$XDG_CONFIG_HOME/backup2usb/backup.toml` first, then
+/// the current directory. The first file found is used, so a file under
+/// `$XDG_CONFIG_HOME` takes precedence over one in the current directory.
+fn config_file_path() -> Option<PathBuf> {
+    if let Some(xdg) = std::env::var_os("XDG_CONFIG_HOME") {
+        let path = PathBuf::from(xdg).join("backup2usb").join("backup.toml");
+        if path.is_file() {
+            return Some(path);
         }
     }
+
+    let cwd_path = PathBuf::from("backup.toml");
+    if cwd_path.is_file() {
+        return Some(cwd_path);
+    }
+
+    None
 }
 
 impl Config {
-    /// Load config from environment and files
+    /// Load config from `backup.toml` (if found) layered under environment
+    /// variables, which always take priority so a one-off override doesn't
+    /// require editing the file.
     pub fn load() -> Result<Self> {
-        let mut config = Config::default();
-        
+        let mut config = match config_file_path() {
+            Some(path) => {
+                let contents = std::fs::read_to_string(&path)
+                    .map_err(|e| anyhow::anyhow!("failed to read {}: {}", path.display(), e))?;
+                toml::from_str(&contents)
+                    .map_err(|e| anyhow::anyhow!("failed to parse {}: {}", path.display(), e))?
+            }
+            None => Config::default(),
+        };
+
         // Override with environment variables
         if let Ok(port) = std::env::var("BACKUP_PORT") {
             config.port = port.parse()?;
@@ -83,7 +217,16 @@ impl Config {
         if let Ok(dest) = std::env::var("BACKUP_DEST") {
             config.backup_dest = PathBuf::from(dest);
         }
-        
+
+        // Destination disks, colon-separated like `PATH`, replacing the
+        // list. Falls back to the single `backup_dest` above if unset, so
+        // existing single-disk setups are unaffected.
+        if let Ok(dests) = std::env::var("BACKUP_DESTINATIONS") {
+            config.backup_destinations = std::env::split_paths(&dests).collect();
+        } else {
+            config.backup_destinations = vec![config.backup_dest.clone()];
+        }
+
         if let Ok(workers) = std::env::var("MAX_WORKERS") {
             config.max_workers = workers.parse()?;
         }
@@ -91,12 +234,84 @@ impl Config {
         if let Ok(scaling) = std::env::var("DYNAMIC_SCALING") {
             config.dynamic_scaling = scaling.parse()?;
         }
-        
+
+        if let Ok(parallelism) = std::env::var("PARALLELISM") {
+            config.default_parallelism = parallelism.parse()?;
+        }
+
+        if let Ok(retries) = std::env::var("RSYNC_MAX_RETRIES") {
+            config.rsync_max_retries = retries.parse()?;
+        }
+
+        if let Ok(verify) = std::env::var("VERIFY_AFTER_BACKUP") {
+            config.verify_after_backup = verify.parse()?;
+        }
+
+        if let Ok(timeout) = std::env::var("STALL_TIMEOUT_SECS") {
+            config.stall_timeout_secs = timeout.parse()?;
+        }
+
+        if let Ok(timeout) = std::env::var("WORKER_DEAD_TIMEOUT_SECS") {
+            config.worker_dead_timeout_secs = timeout.parse()?;
+        }
+
+        if let Ok(interval) = std::env::var("SCRUB_INTERVAL_SECS") {
+            config.scrub_interval_secs = interval.parse()?;
+        }
+
+        if let Ok(retries) = std::env::var("TASK_MAX_RETRIES") {
+            config.task_max_retries = retries.parse()?;
+        }
+
+        if let Ok(bwlimit) = std::env::var("BWLIMIT_KBPS") {
+            config.bwlimit_kbps = bwlimit.parse()?;
+        }
+
+        if let Ok(streams) = std::env::var("PARALLEL_STREAMS") {
+            config.parallel_streams = streams.parse()?;
+        }
+
+        if let Ok(verify_blake2b) = std::env::var("VERIFY_BLAKE2B") {
+            config.verify_blake2b = verify_blake2b.parse()?;
+        }
+
+        if let Ok(digest_size) = std::env::var("VERIFY_DIGEST_SIZE") {
+            config.verify_digest_size = digest_size.parse()?;
+        }
+
+        if let Ok(tranquility) = std::env::var("TRANQUILITY") {
+            config.tranquility = tranquility.parse()?;
+        }
+
+        if let Ok(bucket) = std::env::var("S3_BUCKET") {
+            config.s3_bucket = Some(bucket);
+        }
+        if let Ok(endpoint) = std::env::var("S3_ENDPOINT") {
+            config.s3_endpoint = Some(endpoint);
+        }
+        if let Ok(region) = std::env::var("S3_REGION") {
+            config.s3_region = Some(region);
+        }
+        if let Ok(prefix) = std::env::var("S3_PREFIX") {
+            config.s3_prefix = Some(prefix);
+        }
+        if let Ok(key) = std::env::var("S3_ACCESS_KEY_ID") {
+            config.s3_access_key_id = Some(key);
+        }
+        if let Ok(secret) = std::env::var("S3_SECRET_ACCESS_KEY") {
+            config.s3_secret_access_key = Some(secret);
+        }
+
         // Auto-detect workers if not set
         if config.max_workers == 0 {
             config.max_workers = num_cpus::get();
         }
-        
+
+        // Auto-detect default parallelism if not set (half the workers, efficient for mixed I/O+CPU rsync jobs)
+        if config.default_parallelism == 0 {
+            config.default_parallelism = std::cmp::max(1, config.max_workers / 2);
+        }
+
         Ok(config)
     }
 }
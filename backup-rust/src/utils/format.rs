@@ -0,0 +1,143 @@
+//! Turns the raw numbers the rsync progress parser produces (bytes done,
+//! bytes/sec) into the compact, human-facing strings the UI shows for
+//! throughput and ETA.
+
+/// Exponential moving average over successive throughput samples
+/// (bytes/sec), so an ETA computed from it doesn't jump wildly between
+/// individual rsync progress lines the way a raw instantaneous sample
+/// would. `alpha` controls how much weight the newest sample gets; `0.3`
+/// is a reasonable default - responsive to real speed changes but not
+/// noisy.
+pub struct SpeedSmoother {
+    alpha: f64,
+    smoothed: Option<f64>,
+}
+
+impl SpeedSmoother {
+    pub fn new(alpha: f64) -> Self {
+        Self { alpha, smoothed: None }
+    }
+
+    /// Folds in a new instantaneous sample and returns the updated average.
+    /// The first sample seeds the average outright, since there's nothing
+    /// to blend it with yet.
+    pub fn update(&mut self, sample_bytes_per_sec: f64) -> f64 {
+        let smoothed = match self.smoothed {
+            Some(previous) => self.alpha * sample_bytes_per_sec + (1.0 - self.alpha) * previous,
+            None => sample_bytes_per_sec,
+        };
+        self.smoothed = Some(smoothed);
+        smoothed
+    }
+
+    pub fn current(&self) -> f64 {
+        self.smoothed.unwrap_or(0.0)
+    }
+}
+
+impl Default for SpeedSmoother {
+    fn default() -> Self {
+        Self::new(0.3)
+    }
+}
+
+/// Formats a throughput figure like `45.2 MB/s`.
+pub fn format_throughput(bytes_per_sec: f64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    const GB: f64 = MB * 1024.0;
+
+    if bytes_per_sec >= GB {
+        format!("{:.1} GB/s", bytes_per_sec / GB)
+    } else if bytes_per_sec >= MB {
+        format!("{:.1} MB/s", bytes_per_sec / MB)
+    } else if bytes_per_sec >= KB {
+        format!("{:.1} KB/s", bytes_per_sec / KB)
+    } else {
+        format!("{:.0} B/s", bytes_per_sec.max(0.0))
+    }
+}
+
+/// Breaks `total_seconds` into weeks/days/hours/minutes/seconds and renders
+/// up to three units starting from the most-significant nonzero one, zeros
+/// included, e.g. `1h 23m 4s`, `2d 3h 0m` or `1m 23s`. Sub-second tails are rounded to the nearest second rather than
+/// truncated, so `59.6s` reads as `1m 0s` instead of `59s`.
+pub fn format_duration_human(total_seconds: f64) -> String {
+    const MINUTE: u64 = 60;
+    const HOUR: u64 = 60 * MINUTE;
+    const DAY: u64 = 24 * HOUR;
+    const WEEK: u64 = 7 * DAY;
+
+    let mut remaining = total_seconds.max(0.0).round() as u64;
+    let weeks = remaining / WEEK;
+    remaining %= WEEK;
+    let days = remaining / DAY;
+    remaining %= DAY;
+    let hours = remaining / HOUR;
+    remaining %= HOUR;
+    let minutes = remaining / MINUTE;
+    let seconds = remaining % MINUTE;
+
+    let units = [("w", weeks), ("d", days), ("h", hours), ("m", minutes), ("s", seconds)];
+
+    // Skip leading zero units, then take the first three starting from the
+    // most-significant nonzero one, padding in any zeros that fall between
+    // it and the less-significant units we keep.
+    let mut parts = Vec::with_capacity(3);
+    for (suffix, value) in units {
+        if value > 0 || !parts.is_empty() {
+            parts.push(format!("{}{}", value, suffix));
+        }
+        if parts.len() == 3 {
+            break;
+        }
+    }
+
+    if parts.is_empty() {
+        return "0s".to_string();
+    }
+
+    parts.join(" ")
+}
+
+/// Compact ETA string for `remaining_bytes` at `smoothed_speed_bytes_per_sec`
+/// (see `SpeedSmoother`), e.g. `1h 23m 4s`.
+pub fn format_eta(remaining_bytes: u64, smoothed_speed_bytes_per_sec: f64) -> String {
+    if smoothed_speed_bytes_per_sec <= 0.0 {
+        return "calculating...".to_string();
+    }
+
+    format_duration_human(remaining_bytes as f64 / smoothed_speed_bytes_per_sec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration_human() {
+        assert_eq!(format_duration_human(4.0), "4s");
+        assert_eq!(format_duration_human(83.0), "1m 23s");
+        assert_eq!(format_duration_human(4984.0), "1h 23m 4s");
+        assert_eq!(format_duration_human(59.6), "1m 0s");
+        assert_eq!(format_duration_human(0.0), "0s");
+    }
+
+    #[test]
+    fn test_format_throughput() {
+        assert_eq!(format_throughput(45.2 * 1_048_576.0), "45.2 MB/s");
+        assert_eq!(format_throughput(512.0), "512 B/s");
+    }
+
+    #[test]
+    fn test_speed_smoother_seeds_from_first_sample() {
+        let mut smoother = SpeedSmoother::new(0.3);
+        assert_eq!(smoother.update(100.0), 100.0);
+        assert_eq!(smoother.update(200.0), 0.3 * 200.0 + 0.7 * 100.0);
+    }
+
+    #[test]
+    fn test_format_eta_unknown_speed() {
+        assert_eq!(format_eta(1024, 0.0), "calculating...");
+    }
+}
@@ -0,0 +1,376 @@
+use crate::storage::Storage;
+use crate::utils::log_buffer::{LogBuffer, LogEntry};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// Fields carried by a `backup_session` span (or inherited from its parent)
+/// that tag every event emitted underneath it.
+#[derive(Debug, Clone, Default)]
+struct SpanFields {
+    session_id: Option<String>,
+    directory: Option<String>,
+}
+
+/// Captures `session_id`/`directory` fields from span attributes or event
+/// fields, plus the formatted `message` field of an event.
+#[derive(Default)]
+struct FieldVisitor {
+    fields: SpanFields,
+    message: Option<String>,
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_debug(field, &value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        match field.name() {
+            "session_id" => self.fields.session_id = Some(format!("{:?}", value).trim_matches('"').to_string()),
+            "directory" => self.fields.directory = Some(format!("{:?}", value).trim_matches('"').to_string()),
+            "message" => self.message = Some(format!("{:?}", value).trim_matches('"').to_string()),
+            _ => {}
+        }
+    }
+}
+
+struct LogRecord {
+    session_id: String,
+    level: String,
+    message: String,
+    directory: Option<String>,
+}
+
+/// Tracing layer that streams `info!`/`warn!`/`error!` events into
+/// `backup_logs`, tagging each row with the `session_id` (and optional
+/// `directory`) carried by the enclosing `backup_session` span instead of
+/// requiring every call site to pass it explicitly.
+pub struct DbLogLayer {
+    tx: mpsc::UnboundedSender<LogRecord>,
+}
+
+impl DbLogLayer {
+    /// Spawn the background writer task and return the layer that feeds it.
+    pub fn new(storage: Storage) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel::<LogRecord>();
+
+        tokio::spawn(async move {
+            while let Some(record) = rx.recv().await {
+                if let Err(e) = storage
+                    .add_log(&record.session_id, &record.level, &record.message, record.directory.as_deref())
+                    .await
+                {
+                    eprintln!("Failed to persist tracing event to backup_logs: {}", e);
+                }
+            }
+        });
+
+        Self { tx }
+    }
+}
+
+impl<S> Layer<S> for DbLogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("span must exist in registry");
+
+        // Inherit fields from the parent span (e.g. a per-directory span
+        // nested inside the per-session span), then overlay this span's own.
+        let mut fields = span
+            .parent()
+            .and_then(|parent| parent.extensions().get::<SpanFields>().cloned())
+            .unwrap_or_default();
+
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        if visitor.fields.session_id.is_some() {
+            fields.session_id = visitor.fields.session_id;
+        }
+        if visitor.fields.directory.is_some() {
+            fields.directory = visitor.fields.directory;
+        }
+
+        span.extensions_mut().insert(fields);
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        // Only INFO/WARN/ERROR are persisted; DEBUG/TRACE stay stdout-only.
+        if *event.metadata().level() > Level::INFO {
+            return;
+        }
+
+        let mut fields = ctx
+            .lookup_current()
+            .and_then(|span| span.extensions().get::<SpanFields>().cloned())
+            .unwrap_or_default();
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        if visitor.fields.session_id.is_some() {
+            fields.session_id = visitor.fields.session_id;
+        }
+        if visitor.fields.directory.is_some() {
+            fields.directory = visitor.fields.directory;
+        }
+
+        let Some(session_id) = fields.session_id else {
+            // No enclosing backup-session span; nothing to attach this event to.
+            return;
+        };
+
+        let Some(message) = visitor.message else {
+            return;
+        };
+
+        let level = match *event.metadata().level() {
+            Level::ERROR => "error",
+            Level::WARN => "warn",
+            _ => "info",
+        };
+
+        let _ = self.tx.send(LogRecord {
+            session_id,
+            level: level.to_string(),
+            message,
+            directory: fields.directory,
+        });
+    }
+}
+
+/// Tracing layer that streams `info!`/`warn!`/`error!` events emitted
+/// inside a `process_directory` span into the in-memory `LogBuffer` the
+/// `/logs` API polls, tagging each entry with the `directory` field that
+/// span carries. This replaces the old pattern of `BackupWorker` calling
+/// `log_buffer.add_log(...)` by hand next to almost every tracing call -
+/// events with no enclosing `process_directory` span (nothing set its
+/// `directory` field) are left alone, since they have nothing to attribute
+/// a log line to.
+pub struct LogBufferLayer {
+    log_buffer: LogBuffer,
+}
+
+impl LogBufferLayer {
+    pub fn new(log_buffer: LogBuffer) -> Self {
+        Self { log_buffer }
+    }
+}
+
+impl<S> Layer<S> for LogBufferLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("span must exist in registry");
+
+        let mut fields = span
+            .parent()
+            .and_then(|parent| parent.extensions().get::<SpanFields>().cloned())
+            .unwrap_or_default();
+
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        if visitor.fields.directory.is_some() {
+            fields.directory = visitor.fields.directory;
+        }
+
+        span.extensions_mut().insert(fields);
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        // Only INFO/WARN/ERROR reach the UI log; DEBUG/TRACE stay stdout-only.
+        if *event.metadata().level() > Level::INFO {
+            return;
+        }
+
+        let directory = ctx
+            .lookup_current()
+            .and_then(|span| span.extensions().get::<SpanFields>().cloned())
+            .and_then(|fields| fields.directory);
+
+        let Some(directory) = directory else {
+            return;
+        };
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let Some(message) = visitor.message else {
+            return;
+        };
+
+        let level = match *event.metadata().level() {
+            Level::ERROR => "error",
+            Level::WARN => "warn",
+            _ => "info",
+        };
+
+        self.log_buffer.add_log(level, message, Some(directory));
+    }
+}
+
+struct FileLogRecord {
+    session_id: String,
+    directory: String,
+    level: String,
+    message: String,
+}
+
+/// Per-directory warning counts, shared between `DirectoryFileLogLayer` and
+/// `BackupManager::get_status` so `Directory::warning_count` can be
+/// refreshed from the same map the layer writes into.
+pub type WarningCounts = Arc<RwLock<HashMap<String, u32>>>;
+
+/// Tracing layer that appends `info!`/`warn!`/`error!` events emitted inside
+/// a `process_directory` span to their own file,
+/// `<backup_dest>/.backup-logs/<session_id>/<directory>.log`, so a
+/// directory's complete log survives past the bounded in-memory `LogBuffer`
+/// and `BackupManager::get_directory_log` can read one back on its own. File
+/// writes happen on a background task (mirrors `DbLogLayer`) since
+/// `Layer::on_event` can't await.
+pub struct DirectoryFileLogLayer {
+    tx: mpsc::UnboundedSender<FileLogRecord>,
+}
+
+impl DirectoryFileLogLayer {
+    /// Returns the layer to install on the subscriber, plus the warning-count
+    /// map to hand to `BackupManager`.
+    pub fn new(backup_dest: PathBuf) -> (Self, WarningCounts) {
+        let warning_counts: WarningCounts = Arc::new(RwLock::new(HashMap::new()));
+        let (tx, mut rx) = mpsc::unbounded_channel::<FileLogRecord>();
+        let counts = warning_counts.clone();
+
+        tokio::spawn(async move {
+            while let Some(record) = rx.recv().await {
+                if record.level == "warn" {
+                    *counts.write().unwrap().entry(record.directory.clone()).or_insert(0) += 1;
+                }
+
+                let session_dir = backup_dest.join(".backup-logs").join(&record.session_id);
+                if let Err(e) = tokio::fs::create_dir_all(&session_dir).await {
+                    eprintln!("Failed to create directory log dir {:?}: {}", session_dir, e);
+                    continue;
+                }
+
+                let path = session_dir.join(format!("{}.log", record.directory));
+                let line = format!(
+                    "{} [{}] {}\n",
+                    chrono::Utc::now().to_rfc3339(),
+                    record.level,
+                    record.message
+                );
+
+                let file = tokio::fs::OpenOptions::new().create(true).append(true).open(&path).await;
+                match file {
+                    Ok(mut file) => {
+                        if let Err(e) = file.write_all(line.as_bytes()).await {
+                            eprintln!("Failed to append to directory log {:?}: {}", path, e);
+                        }
+                    }
+                    Err(e) => eprintln!("Failed to open directory log {:?}: {}", path, e),
+                }
+            }
+        });
+
+        (Self { tx }, warning_counts)
+    }
+}
+
+impl<S> Layer<S> for DirectoryFileLogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("span must exist in registry");
+
+        let mut fields = span
+            .parent()
+            .and_then(|parent| parent.extensions().get::<SpanFields>().cloned())
+            .unwrap_or_default();
+
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        if visitor.fields.session_id.is_some() {
+            fields.session_id = visitor.fields.session_id;
+        }
+        if visitor.fields.directory.is_some() {
+            fields.directory = visitor.fields.directory;
+        }
+
+        span.extensions_mut().insert(fields);
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        // Only INFO/WARN/ERROR go to the file; DEBUG/TRACE stay stdout-only.
+        if *event.metadata().level() > Level::INFO {
+            return;
+        }
+
+        let fields = ctx
+            .lookup_current()
+            .and_then(|span| span.extensions().get::<SpanFields>().cloned())
+            .unwrap_or_default();
+
+        let (Some(session_id), Some(directory)) = (fields.session_id, fields.directory) else {
+            // Needs both an enclosing session and directory span to know
+            // which file this event belongs to.
+            return;
+        };
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let Some(message) = visitor.message else {
+            return;
+        };
+
+        let level = match *event.metadata().level() {
+            Level::ERROR => "error",
+            Level::WARN => "warn",
+            _ => "info",
+        };
+
+        let _ = self.tx.send(FileLogRecord {
+            session_id,
+            directory,
+            level: level.to_string(),
+            message,
+        });
+    }
+}
+
+/// Reads a directory's log file back, written by `DirectoryFileLogLayer` at
+/// `<backup_dest>/.backup-logs/<session_id>/<directory>.log`. An empty
+/// `Vec` (including when the file doesn't exist) means nothing has been
+/// logged for that directory in this session yet.
+pub async fn read_directory_log(backup_dest: &std::path::Path, session_id: &str, directory: &str) -> Vec<LogEntry> {
+    let path = backup_dest.join(".backup-logs").join(session_id).join(format!("{}.log", directory));
+
+    let contents = match tokio::fs::read_to_string(&path).await {
+        Ok(contents) => contents,
+        Err(_) => return Vec::new(),
+    };
+
+    contents
+        .lines()
+        .filter_map(|line| {
+            let (timestamp_part, rest) = line.split_once(' ')?;
+            let (level_part, message) = rest.split_once("] ")?;
+            let level = level_part.trim_start_matches('[');
+            Some(LogEntry {
+                timestamp: chrono::DateTime::parse_from_rfc3339(timestamp_part).ok()?.timestamp(),
+                level: level.to_string(),
+                message: message.to_string(),
+                directory: Some(directory.to_string()),
+            })
+        })
+        .collect()
+}
@@ -1,3 +1,8 @@
+use crate::backup::task_log::TaskLogLayer;
+use crate::storage::Storage;
+use crate::utils::log_buffer::LogBuffer;
+use crate::utils::log_layer::{DbLogLayer, DirectoryFileLogLayer, LogBufferLayer, WarningCounts};
+use std::path::PathBuf;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 pub fn init_tracing() {
@@ -8,4 +13,38 @@ pub fn init_tracing() {
         )
         .with(tracing_subscriber::fmt::layer())
         .init();
+}
+
+/// Like `init_tracing`, but also installs a `DbLogLayer` that streams
+/// `info!`/`warn!`/`error!` events tagged with a `backup_session` span into
+/// `backup_logs`, a `LogBufferLayer` that does the same for events tagged
+/// with a `process_directory` span into an in-memory `LogBuffer`, a
+/// `TaskLogLayer` keyed on the `task` span's `task_id` field, and a
+/// `DirectoryFileLogLayer` that appends those same `process_directory`
+/// events to a per-directory file under `backup_dest` - replacing the old
+/// pattern of manually calling `Storage::add_log` / `LogBuffer::add_log`
+/// from call sites. Returns the `TaskLogRegistry`, `LogBuffer` and
+/// `WarningCounts` handles so `BackupManager` can read from the same maps
+/// the layers write into.
+pub fn init_tracing_with_storage(
+    storage: Storage,
+    backup_dest: PathBuf,
+) -> (crate::backup::task_log::TaskLogRegistry, LogBuffer, WarningCounts) {
+    let (task_log_layer, task_log_registry) = TaskLogLayer::new(200);
+    let log_buffer = LogBuffer::new(1000); // Keep last 1000 log entries
+    let (directory_file_log_layer, warning_counts) = DirectoryFileLogLayer::new(backup_dest);
+
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| "backup_system=info,tower_http=info".into()),
+        )
+        .with(tracing_subscriber::fmt::layer())
+        .with(DbLogLayer::new(storage))
+        .with(LogBufferLayer::new(log_buffer.clone()))
+        .with(task_log_layer)
+        .with(directory_file_log_layer)
+        .init();
+
+    (task_log_registry, log_buffer, warning_counts)
 }
\ No newline at end of file
@@ -1,7 +1,11 @@
 pub mod config;
 pub mod disk;
+pub mod format;
 pub mod logging;
+pub mod log_layer;
 pub mod resource_monitor;
 pub mod log_buffer;
+pub mod tranquilizer;
 
-pub use resource_monitor::{ResourceMonitor, ResourceConfig};
\ No newline at end of file
+pub use resource_monitor::{ResourceMonitor, ResourceConfig};
+pub use tranquilizer::Tranquilizer;
\ No newline at end of file
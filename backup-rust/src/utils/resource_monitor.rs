@@ -4,6 +4,14 @@ use std::sync::Arc;
 use std::sync::RwLock;
 use tracing::{debug, info, warn};
 
+// halcasteel/backup2usb-chromeos#chunk2-wontdo: chunk2-1..5 and chunk2-7 built
+// exponentially-smoothed scaling with hysteresis, worker introspection,
+// tranquility throttling, a retry policy, Tokio runtime metrics, and
+// CPU/IO WorkloadProfile pools on top of this struct, but nothing in the
+// pipeline main.rs runs ever constructs a ResourceMonitor - task_manager.rs
+// has its own independent scaling/tranquility/retry logic already wired in.
+// Closed won't-do rather than replacing that already-working implementation;
+// see 209f238 for the revert back to this baseline.
 /// Dynamic resource monitor that adjusts worker count based on system resources
 pub struct ResourceMonitor {
     system: Arc<RwLock<System>>,
@@ -0,0 +1,85 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Paces a worker's duty cycle so it doesn't run flat-out, mirroring
+/// Garage's tranquilizer: after each active burst, sleep for
+/// `elapsed * tranquility` so the duty cycle settles at `1/(1+tranquility)`.
+/// A `tranquility` of 0 means full speed (no sleep).
+pub struct Tranquilizer {
+    /// Moving window of recent active-burst durations, smoothing out jitter
+    /// from one unusually short or long burst.
+    window: VecDeque<Duration>,
+    window_size: usize,
+    active_since: Option<Instant>,
+}
+
+impl Tranquilizer {
+    pub fn new() -> Self {
+        Self {
+            window: VecDeque::with_capacity(5),
+            window_size: 5,
+            active_since: None,
+        }
+    }
+
+    /// Mark the start of an active burst of work.
+    pub fn begin(&mut self) {
+        self.active_since = Some(Instant::now());
+    }
+
+    /// End the current burst and sleep long enough that the duty cycle
+    /// settles at `1/(1+tranquility)`. No-op if `begin()` was never called
+    /// or `tranquility` is 0.
+    pub async fn tranquilize(&mut self, tranquility: u32) {
+        let Some(started) = self.active_since.take() else {
+            return;
+        };
+        let elapsed = started.elapsed();
+
+        self.window.push_back(elapsed);
+        if self.window.len() > self.window_size {
+            self.window.pop_front();
+        }
+
+        if tranquility == 0 {
+            return;
+        }
+
+        let smoothed = self.window.iter().sum::<Duration>() / self.window.len() as u32;
+        let sleep_for = smoothed * tranquility;
+        if !sleep_for.is_zero() {
+            tokio::time::sleep(sleep_for).await;
+        }
+    }
+}
+
+impl Default for Tranquilizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn zero_tranquility_does_not_sleep() {
+        let mut t = Tranquilizer::new();
+        t.begin();
+        let start = Instant::now();
+        t.tranquilize(0).await;
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn higher_tranquility_sleeps_longer() {
+        let mut t = Tranquilizer::new();
+        t.begin();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let start = Instant::now();
+        t.tranquilize(2).await;
+        // duty cycle settles at 1/(1+2): sleep ~= 2x the active burst.
+        assert!(start.elapsed() >= Duration::from_millis(30));
+    }
+}
@@ -5,11 +5,31 @@ use axum::{
     Router,
 };
 use futures::{sink::SinkExt, stream::StreamExt};
+use serde::Deserialize;
 use serde_json::json;
-use tracing::{debug, error};
+use tracing::{debug, error, warn};
 
 use crate::backup::BackupManager;
 
+/// Commands a client can send over the socket to control a running backup,
+/// mirroring the single-control-channel design `/control` and `/verify`
+/// already use over HTTP - this just gives the same actions a push-based
+/// transport instead of a request/response one.
+#[derive(Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+enum WsCommand {
+    Pause,
+    Resume,
+    AbortDirectory { index: usize },
+    /// Parsed and accepted, but not actually honored yet - see the
+    /// `SetWorkers` match arm below. Live rescaling needs a worker pool
+    /// that can grow/shrink after `TaskManager::start`, which doesn't
+    /// exist in this tree; operators can't control worker count over the
+    /// socket despite this variant parsing successfully.
+    SetWorkers { count: usize },
+    SetTranquility { value: u8 },
+}
+
 pub fn routes() -> Router<BackupManager> {
     Router::new()
         .route("/", get(websocket_handler))
@@ -79,6 +99,36 @@ async fn handle_socket(socket: WebSocket, manager: BackupManager) {
                                 "message": message
                             })
                         }
+                        super::super::backup::manager::Event::WorkerStateChanged { id, info } => {
+                            json!({
+                                "type": "worker_state_changed",
+                                "id": id,
+                                "worker": info
+                            })
+                        }
+                        super::super::backup::manager::Event::TranquilityChanged { value } => {
+                            json!({
+                                "type": "tranquility_changed",
+                                "value": value
+                            })
+                        }
+                        super::super::backup::manager::Event::VerifyProgress { index, progress } => {
+                            json!({
+                                "type": "verify_progress",
+                                "index": index,
+                                "progress": progress
+                            })
+                        }
+                        super::super::backup::manager::Event::ScanProgress { index, total, name, size, file_count } => {
+                            json!({
+                                "type": "scan_progress",
+                                "index": index,
+                                "total": total,
+                                "name": name,
+                                "size": size,
+                                "file_count": file_count
+                            })
+                        }
                     };
                     
                     if sender.send(Message::Text(msg.to_string())).await.is_err() {
@@ -103,12 +153,45 @@ async fn handle_socket(socket: WebSocket, manager: BackupManager) {
     });
     
     // Handle incoming messages
+    let manager_for_recv = manager.clone();
     let mut recv_task = tokio::spawn(async move {
         while let Some(Ok(msg)) = receiver.next().await {
             match msg {
                 Message::Text(text) => {
                     debug!("Received WebSocket message: {}", text);
-                    // Handle client messages if needed
+                    match serde_json::from_str::<WsCommand>(&text) {
+                        Ok(WsCommand::Pause) => {
+                            if let Err(e) = manager_for_recv.pause().await {
+                                error!("WebSocket pause command failed: {}", e);
+                            }
+                        }
+                        Ok(WsCommand::Resume) => {
+                            if let Err(e) = manager_for_recv.resume().await {
+                                error!("WebSocket resume command failed: {}", e);
+                            }
+                        }
+                        Ok(WsCommand::AbortDirectory { index }) => {
+                            if !manager_for_recv.cancel_directory(index) {
+                                warn!("WebSocket abort_directory: no queued or running task for directory {}", index);
+                            }
+                        }
+                        Ok(WsCommand::SetTranquility { value }) => {
+                            if let Err(e) = manager_for_recv.set_tranquility(value).await {
+                                error!("WebSocket set_tranquility command failed: {}", e);
+                            }
+                        }
+                        Ok(WsCommand::SetWorkers { count }) => {
+                            // Known-incomplete: the worker pool is sized once in
+                            // `TaskManager::start` and has no live respawn primitive,
+                            // so a rescale request can't be honored yet. Log it rather
+                            // than silently dropping it so this doesn't look done when
+                            // it isn't.
+                            warn!("WebSocket set_workers: live worker rescaling to {} is not yet supported", count);
+                        }
+                        Err(e) => {
+                            warn!("Ignoring unrecognized WebSocket command {:?}: {}", text, e);
+                        }
+                    }
                 }
                 Message::Close(_) => {
                     debug!("WebSocket closed by client");